@@ -2,13 +2,17 @@
 //
 // It was written by Alexander van Saase <avsaase [at] gmail.com>
 
-use iced::widget::{checkbox, container, pick_list, row, slider};
+use iced::widget::{checkbox, container, pick_list, row, slider, text};
 use iced::Padding;
 use iced::{
     alignment::{Horizontal, Vertical},
     Color, Element, Length,
 };
-use iced_aw::{grid, grid_row};
+use iced_aw::{
+    grid,
+    grid::{Breadth, Track},
+    grid_row, GridRow,
+};
 
 struct App {
     horizontal_alignment: Horizontal,
@@ -104,13 +108,22 @@ impl App {
             grid_row!("Column spacing", col_spacing_slider),
             grid_row!("Fill space", fill_checkboxes),
             grid_row!("Padding", padding_slider),
-            grid_row!("Debug mode", debug_mode_check)
+            grid_row!("Debug mode", debug_mode_check),
+            GridRow::new().push_spanning(
+                text("This cell spans both columns")
+                    .width(Length::Fill)
+                    .center(),
+                1,
+                2,
+            )
         )
         .horizontal_alignment(self.horizontal_alignment)
         .vertical_alignment(self.vertical_alignment)
         .row_spacing(self.row_spacing)
         .column_spacing(self.column_spacing)
-        .padding(Padding::new(self.padding));
+        .padding(Padding::new(self.padding))
+        // The labels fit the widest label and the inputs take the remaining space.
+        .column_widths(&[Track::auto(), Track::minmax(200.0, Breadth::Fraction(1.0))]);
 
         if self.fill_width {
             grid = grid.width(Length::Fill);
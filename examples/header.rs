@@ -0,0 +1,105 @@
+// This example demonstrates how to use the `header` widget to resize the
+// columns of a list by dragging the dividers between its column titles.
+
+use iced::{
+    widget::{column, container, row, scrollable, text},
+    Element, Length,
+};
+use iced_aw::{header::ResizeEvent, Header};
+
+fn main() -> iced::Result {
+    iced::application("Header example", HeaderExample::update, HeaderExample::view).run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Resize(ResizeEvent),
+}
+
+struct HeaderExample {
+    /// The names and widths of the columns.
+    columns: Vec<(&'static str, f32)>,
+    files: Vec<[String; 3]>,
+    status: String,
+}
+
+impl Default for HeaderExample {
+    fn default() -> Self {
+        Self {
+            columns: vec![("Name", 200.0), ("Kind", 120.0), ("Size", 100.0)],
+            files: (1..=30)
+                .map(|idx| {
+                    [
+                        format!("file_{idx}.txt"),
+                        String::from("Text"),
+                        format!("{} KB", idx * 3),
+                    ]
+                })
+                .collect(),
+            status: String::from("Drag the dividers between the column titles"),
+        }
+    }
+}
+
+impl HeaderExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Resize(ResizeEvent::ResizeColumn {
+                left_name,
+                left_width,
+                right_name,
+                right_width,
+            }) => {
+                for (name, width) in &mut self.columns {
+                    if *name == left_name {
+                        *width = f32::from(left_width);
+                    } else if *name == right_name {
+                        *width = f32::from(right_width);
+                    }
+                }
+            }
+            Message::Resize(ResizeEvent::Finished) => {
+                self.status = self
+                    .columns
+                    .iter()
+                    .map(|(name, width)| format!("{name}: {width}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let header = Header::new(
+            self.columns
+                .iter()
+                .map(|(name, width)| {
+                    (
+                        (*name).to_owned(),
+                        container(text(*name)).padding(5).width(*width).into(),
+                    )
+                })
+                .collect(),
+            None,
+            Some(Length::Fill),
+        )
+        .on_resize(5, Message::Resize);
+
+        let rows =
+            self.files.iter().map(|file| {
+                row(self.columns.iter().zip(file).map(|((_, width), value)| {
+                    container(text(value)).padding(5).width(*width).into()
+                }))
+                .into()
+            });
+
+        column![
+            header,
+            scrollable(column(rows).width(Length::Fill)).height(Length::Fill),
+            text(&self.status),
+        ]
+        .padding(10)
+        .spacing(5)
+        .into()
+    }
+}
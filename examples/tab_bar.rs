@@ -22,6 +22,7 @@ fn main() -> iced::Result {
 enum Message {
     TabSelected(usize),
     TabClosed(usize),
+    TabMoved(usize, usize),
     TabLabelInputChanged(String),
     TabContentInputChanged(String),
     NewTab,
@@ -52,6 +53,12 @@ impl TabBarExample {
                 };
                 println!("active tab after: {}", self.active_tab);
             }
+            Message::TabMoved(index, new_index) => {
+                let tab = self.tabs.remove(index);
+                self.tabs.insert(new_index, tab);
+                // The dragged tab was selected when it was pressed.
+                self.active_tab = new_index;
+            }
             Message::TabLabelInputChanged(value) => self.new_tab_label = value,
             Message::TabContentInputChanged(value) => self.new_tab_content = value,
             Message::NewTab => {
@@ -104,6 +111,8 @@ impl TabBarExample {
                     )
                     .set_active_tab(&self.active_tab)
                     .on_close(Message::TabClosed)
+                    .on_reorder(Message::TabMoved)
+                    .overflow_menu()
                     .tab_width(Length::Shrink)
                     .spacing(5.0)
                     .padding(5.0)
@@ -0,0 +1,273 @@
+// This example demonstrates how to use the `table` widget.
+//
+// It was written by Yeastplume <yeastplume@gmail.com>
+
+use std::collections::HashSet;
+
+use iced::{
+    widget::{button, checkbox, column, container, row, scrollable, text},
+    Element, Length,
+};
+use iced_aw::{
+    style::table_row,
+    table::{CellEdit, ExportFormat, FilterValue, SortDirection, Table, TableColumn},
+    NumberInput, TypedInput,
+};
+
+fn main() -> iced::Result {
+    iced::application("Table example", TableExample::update, TableExample::view).run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Sort(usize, SortDirection),
+    SelectionChanged(Vec<usize>),
+    Edit(usize, usize, CellEdit),
+    NameChanged(String),
+    AgeChanged(u8),
+    MoveColumn(usize, usize),
+    GroupByDecade(bool),
+    ToggleGroup(String),
+    Filter(usize, FilterValue),
+    Export,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Name,
+    Email,
+    Age,
+}
+
+struct Person {
+    id: usize,
+    name: String,
+    email: String,
+    age: u8,
+}
+
+struct TableExample {
+    /// The people matching the filters come first, in sorted order.
+    people: Vec<Person>,
+    matching: usize,
+    columns: Vec<Field>,
+    sort: Option<(Field, SortDirection)>,
+    selection: Vec<usize>,
+    editing: Option<(usize, Field)>,
+    name: String,
+    age: u8,
+    group_by_decade: bool,
+    collapsed: HashSet<String>,
+    name_filter: String,
+    min_age: f64,
+}
+
+impl Default for TableExample {
+    fn default() -> Self {
+        Self {
+            people: (0..100_000)
+                .map(|i| Person {
+                    id: i,
+                    name: format!("Person {i}"),
+                    email: format!("person{i}@example.com"),
+                    age: 20 + (i * 7 % 50) as u8,
+                })
+                .collect(),
+            matching: 100_000,
+            columns: vec![Field::Name, Field::Email, Field::Age],
+            sort: None,
+            selection: Vec::new(),
+            editing: None,
+            name: String::new(),
+            age: 0,
+            group_by_decade: false,
+            collapsed: HashSet::new(),
+            name_filter: String::new(),
+            min_age: 0.0,
+        }
+    }
+}
+
+impl TableExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Sort(column, direction) => {
+                self.sort = Some((self.columns[column], direction));
+                self.arrange();
+            }
+            Message::SelectionChanged(selection) => self.selection = selection,
+            Message::Edit(id, column, edit) => {
+                let Some(person) = self.people.iter_mut().find(|person| person.id == id) else {
+                    return;
+                };
+
+                match edit {
+                    CellEdit::Start => {
+                        self.name.clone_from(&person.name);
+                        self.age = person.age;
+                        self.editing = Some((id, self.columns[column]));
+                    }
+                    CellEdit::Commit => {
+                        match self.columns[column] {
+                            Field::Name => person.name.clone_from(&self.name),
+                            Field::Email => {}
+                            Field::Age => person.age = self.age,
+                        }
+                        self.editing = None;
+                        self.arrange();
+                    }
+                    CellEdit::Cancel => self.editing = None,
+                }
+            }
+            Message::NameChanged(name) => self.name = name,
+            Message::AgeChanged(age) => self.age = age,
+            Message::MoveColumn(from, to) => {
+                let field = self.columns.remove(from);
+                self.columns.insert(to, field);
+            }
+            Message::GroupByDecade(group_by_decade) => self.group_by_decade = group_by_decade,
+            Message::ToggleGroup(group) => {
+                if !self.collapsed.remove(&group) {
+                    self.collapsed.insert(group);
+                }
+            }
+            Message::Filter(column, value) => {
+                match (self.columns[column], value) {
+                    (Field::Name, FilterValue::Text(name)) => self.name_filter = name,
+                    (Field::Age, FilterValue::Number(age)) => self.min_age = age,
+                    _ => {}
+                }
+                self.arrange();
+            }
+            Message::Export => {
+                let csv = self.table().export(ExportFormat::Csv);
+                if let Err(error) = std::fs::write("people.csv", csv) {
+                    eprintln!("Failed to export the table: {error}");
+                }
+            }
+        }
+    }
+
+    /// Sorts the people and moves the ones matching the filters to the front.
+    fn arrange(&mut self) {
+        self.people.sort_by_key(|person| person.id);
+
+        if let Some((field, direction)) = self.sort {
+            if direction != SortDirection::Unsorted {
+                match field {
+                    Field::Name => self.people.sort_by(|a, b| a.name.cmp(&b.name)),
+                    Field::Email => self.people.sort_by(|a, b| a.email.cmp(&b.email)),
+                    Field::Age => self.people.sort_by_key(|person| person.age),
+                }
+            }
+
+            if direction == SortDirection::Descending {
+                self.people.reverse();
+            }
+        }
+
+        let name_filter = self.name_filter.to_lowercase();
+        let min_age = self.min_age;
+        let matches = |person: &Person| {
+            person.name.to_lowercase().contains(&name_filter) && f64::from(person.age) >= min_age
+        };
+
+        self.people.sort_by_key(|person| !matches(person));
+        self.matching = self
+            .people
+            .iter()
+            .take_while(|person| matches(person))
+            .count();
+    }
+
+    fn table(&self) -> Table<'_, Person, Message> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|field| match field {
+                Field::Name => TableColumn::new("Name", |person: &Person| text(&person.name))
+                    .export(|person| person.name.clone())
+                    .text_filter(&self.name_filter)
+                    .editor(|_| {
+                        TypedInput::new("Name", &self.name)
+                            .on_input(Message::NameChanged)
+                            .width(Length::Fill)
+                    }),
+                Field::Email => TableColumn::new("Email", |person: &Person| text(&person.email))
+                    .export(|person| person.email.clone())
+                    .width(Length::FillPortion(2)),
+                Field::Age => TableColumn::new("Age", |person: &Person| text(person.age))
+                    .width(150.0)
+                    .export(|person| person.age.to_string())
+                    .number_filter(self.min_age, 0.0..=150.0)
+                    .editor(|_| NumberInput::new(self.age, 0..=150, Message::AgeChanged))
+                    .aggregate(|people: &[&Person]| {
+                        let total: f32 = people.iter().map(|person| f32::from(person.age)).sum();
+                        text(format!("avg {:.1}", total / people.len() as f32))
+                    }),
+            })
+            .collect();
+
+        let position = |field| self.columns.iter().position(|f| *f == field);
+
+        let mut table = Table::keyed(columns, &self.people[..self.matching], |person| person.id)
+            .width(1200.0)
+            .header_height(30.0)
+            .row_height(25.0)
+            .row_style(table_row::striped)
+            .virtualized(25.0)
+            .frozen_columns(1)
+            .sticky_header(true)
+            .on_sort(Message::Sort)
+            .selection(self.selection.iter().copied())
+            .on_selection_change(Message::SelectionChanged)
+            .on_cell_edit(Message::Edit)
+            .on_column_move(Message::MoveColumn)
+            .on_filter_change(Message::Filter);
+
+        if self.group_by_decade {
+            table = table
+                .group_by(|person| format!("{}s", person.age / 10 * 10))
+                .collapsed(self.collapsed.iter().cloned())
+                .on_group_toggle(Message::ToggleGroup);
+        }
+
+        if let Some((column, direction)) = self
+            .sort
+            .and_then(|(field, direction)| Some((position(field)?, direction)))
+        {
+            table = table.sort(column, direction);
+        }
+
+        if let Some((id, column)) = self
+            .editing
+            .and_then(|(id, field)| Some((id, position(field)?)))
+        {
+            table = table.editing(&id, column);
+        }
+
+        table
+    }
+
+    fn view(&self) -> Element<Message> {
+        let direction = scrollable::Direction::Both {
+            vertical: scrollable::Scrollbar::default(),
+            horizontal: scrollable::Scrollbar::default(),
+        };
+
+        let group_by_decade =
+            checkbox("Group by decade", self.group_by_decade).on_toggle(Message::GroupByDecade);
+
+        let export = button("Export CSV").on_press(Message::Export);
+
+        container(
+            column![
+                row![group_by_decade, export].spacing(20),
+                scrollable(self.table()).direction(direction)
+            ]
+            .spacing(10),
+        )
+        .padding(10)
+        .into()
+    }
+}
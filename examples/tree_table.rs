@@ -0,0 +1,148 @@
+// This example demonstrates how to use the `table` widget to display a tree
+// whose children are loaded when a node is expanded.
+
+use std::collections::HashSet;
+
+use iced::{
+    widget::{container, scrollable, text},
+    Element,
+};
+use iced_aw::table::{Table, TableColumn};
+
+fn main() -> iced::Result {
+    iced::application(
+        "Tree table example",
+        TreeTableExample::update,
+        TreeTableExample::view,
+    )
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Expand(String),
+    Collapse(String),
+    SelectionChanged(Vec<String>),
+}
+
+struct Entry {
+    path: String,
+    name: String,
+    size: u64,
+    /// The children of a directory, `None` for files and `Some` empty
+    /// directories until they are loaded.
+    children: Option<Vec<Entry>>,
+    loaded: bool,
+}
+
+impl Entry {
+    fn file(path: &str, size: u64) -> Self {
+        Self::new(path, size, None)
+    }
+
+    fn directory(path: &str) -> Self {
+        Self::new(path, 0, Some(Vec::new()))
+    }
+
+    fn new(path: &str, size: u64, children: Option<Vec<Entry>>) -> Self {
+        Self {
+            path: path.to_owned(),
+            name: path.rsplit('/').next().unwrap_or(path).to_owned(),
+            size,
+            children,
+            loaded: false,
+        }
+    }
+
+    fn find_mut<'a>(entries: &'a mut [Entry], path: &str) -> Option<&'a mut Entry> {
+        entries.iter_mut().find_map(|entry| {
+            if entry.path == path {
+                Some(entry)
+            } else {
+                Self::find_mut(entry.children.as_deref_mut()?, path)
+            }
+        })
+    }
+
+    /// Pretends to read the directory at the path of the entry.
+    fn load(&mut self) {
+        if self.loaded {
+            return;
+        }
+
+        let path = &self.path;
+        self.children = Some(vec![
+            Entry::directory(&format!("{path}/src")),
+            Entry::directory(&format!("{path}/assets")),
+            Entry::file(&format!("{path}/README.md"), 1_024),
+            Entry::file(&format!("{path}/Cargo.toml"), 512),
+        ]);
+        self.loaded = true;
+    }
+}
+
+struct TreeTableExample {
+    roots: Vec<Entry>,
+    expanded: HashSet<String>,
+    selection: Vec<String>,
+}
+
+impl Default for TreeTableExample {
+    fn default() -> Self {
+        Self {
+            roots: vec![
+                Entry::directory("home"),
+                Entry::directory("projects"),
+                Entry::file("notes.txt", 2_048),
+            ],
+            expanded: HashSet::new(),
+            selection: Vec::new(),
+        }
+    }
+}
+
+impl TreeTableExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Expand(path) => {
+                if let Some(entry) = Entry::find_mut(&mut self.roots, &path) {
+                    entry.load();
+                }
+                self.expanded.insert(path);
+            }
+            Message::Collapse(path) => {
+                self.expanded.remove(&path);
+            }
+            Message::SelectionChanged(selection) => self.selection = selection,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let columns = vec![
+            TableColumn::new("Name", |entry: &Entry| text(&entry.name)),
+            TableColumn::new("Size", |entry: &Entry| {
+                text(if entry.children.is_some() {
+                    String::from("--")
+                } else {
+                    format!("{} B", entry.size)
+                })
+            })
+            .width(120.0),
+        ];
+
+        let table = Table::tree(
+            columns,
+            &self.roots,
+            |entry| entry.path.clone(),
+            |entry| entry.children.as_deref(),
+            self.expanded.iter().cloned(),
+        )
+        .row_height(25.0)
+        .on_expand(Message::Expand)
+        .on_collapse(Message::Collapse)
+        .selection(self.selection.iter().cloned())
+        .on_selection_change(Message::SelectionChanged);
+
+        container(scrollable(table)).padding(10).into()
+    }
+}
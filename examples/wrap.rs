@@ -8,7 +8,7 @@ use iced::{
     widget::{Button, Column, Container, PickList, Row, Text},
     Element,
 };
-use iced_aw::{NumberInput, Wrap};
+use iced_aw::{wrap::Justify, NumberInput, Wrap};
 use rand::Rng;
 
 fn main() -> iced::Result {
@@ -24,6 +24,7 @@ struct RandStrings {
     line_spacing: f32,
     line_minimal_length: f32,
     align: iced::Alignment,
+    justify: Justify,
 }
 
 impl Default for RandStrings {
@@ -39,6 +40,7 @@ impl Default for RandStrings {
             vbuttons: data.clone(),
             hbuttons: data,
             align: iced::Alignment::Start,
+            justify: Justify::Start,
             spacing: 0.0,
             line_spacing: 0.0,
             line_minimal_length: 10.0,
@@ -79,6 +81,20 @@ impl Display for WrapAlign {
         }
     }
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WrapJustify(Justify);
+impl Display for WrapJustify {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Justify::Start => f.write_str("start"),
+            Justify::Center => f.write_str("center"),
+            Justify::End => f.write_str("end"),
+            Justify::SpaceBetween => f.write_str("space between"),
+            Justify::SpaceAround => f.write_str("space around"),
+            Justify::SpaceEvenly => f.write_str("space evenly"),
+        }
+    }
+}
 #[derive(Debug, Clone)]
 struct StrButton {
     str: String,
@@ -89,6 +105,7 @@ struct StrButton {
 #[derive(Debug, Clone)]
 enum Message {
     ChangeAlign(WrapAlign),
+    ChangeJustify(WrapJustify),
     ChangeSpacing(f32),
     ChangeLineSpacing(f32),
     ChangeMinimalLength(f32),
@@ -100,6 +117,9 @@ impl RandStrings {
             Message::ChangeAlign(align) => {
                 self.align = align.into();
             }
+            Message::ChangeJustify(WrapJustify(justify)) => {
+                self.justify = justify;
+            }
             Message::ChangeSpacing(num) => {
                 self.spacing = num;
             }
@@ -121,9 +141,11 @@ impl RandStrings {
                     wrap.push(Button::new(Text::new(str.as_str()).size(button.size)))
                 })
                 .align_items(self.align)
+                .justify(self.justify)
                 .spacing(self.spacing)
                 .line_spacing(self.line_spacing)
-                .line_minimal_length(self.line_minimal_length),
+                .line_minimal_length(self.line_minimal_length)
+                .height_items(iced::Length::Fill),
         )
         .width(iced::Length::FillPortion(5));
         let horizontal = Container::new(
@@ -134,9 +156,11 @@ impl RandStrings {
                     wrap.push(Button::new(Text::new(str.as_str()).size(button.size)))
                 })
                 .align_items(self.align)
+                .justify(self.justify)
                 .spacing(self.spacing)
                 .line_spacing(self.line_spacing)
-                .line_minimal_length(self.line_minimal_length),
+                .line_minimal_length(self.line_minimal_length)
+                .width_items(iced::Length::Fill),
         )
         .width(iced::Length::FillPortion(5));
         let align_picklist = PickList::new(
@@ -144,6 +168,19 @@ impl RandStrings {
             Some(Into::<WrapAlign>::into(self.align)),
             Message::ChangeAlign,
         );
+        let justify_picklist = PickList::new(
+            [
+                Justify::Start,
+                Justify::Center,
+                Justify::End,
+                Justify::SpaceBetween,
+                Justify::SpaceAround,
+                Justify::SpaceEvenly,
+            ]
+            .map(WrapJustify),
+            Some(WrapJustify(self.justify)),
+            Message::ChangeJustify,
+        );
         let spacing_input = Column::new()
             .push(Text::new("spacing"))
             .push(NumberInput::new(
@@ -169,6 +206,7 @@ impl RandStrings {
                 ));
         let ctrls = Column::new()
             .push(align_picklist)
+            .push(justify_picklist)
             .push(spacing_input)
             .push(line_spacing_input)
             .push(line_minimal_length_input)
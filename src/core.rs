@@ -8,6 +8,12 @@ pub mod clock;
 #[cfg(feature = "color_picker")]
 pub mod color;
 
+#[cfg(any(feature = "grid", feature = "wrap"))]
+pub mod breakpoints;
+
+#[cfg(any(feature = "header", feature = "table"))]
+pub mod resize;
+
 pub mod overlay;
 
 pub mod renderer;
@@ -0,0 +1,65 @@
+//! Values of a widget changing with the width available to it.
+
+/// A list of values each applying from a minimum available width on, like
+/// the CSS media queries of a responsive layout.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakpoints<T> {
+    /// The minimum widths and their values, sorted by width.
+    breakpoints: Vec<(f32, T)>,
+}
+
+impl<T> Breakpoints<T> {
+    /// Creates [`Breakpoints`] from pairs of a minimum width and the value
+    /// applying from it on, in any order.
+    #[must_use]
+    pub fn new(breakpoints: impl IntoIterator<Item = (f32, T)>) -> Self {
+        let mut breakpoints: Vec<_> = breakpoints.into_iter().collect();
+        breakpoints.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Self { breakpoints }
+    }
+
+    /// Returns the value of the largest breakpoint not exceeding the given
+    /// width, if any.
+    #[must_use]
+    pub fn get(&self, width: f32) -> Option<&T> {
+        self.breakpoints
+            .iter()
+            .rev()
+            .find(|(min_width, _)| *min_width <= width)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns whether there are no breakpoints.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.breakpoints.is_empty()
+    }
+}
+
+impl<T> Default for Breakpoints<T> {
+    fn default() -> Self {
+        Self {
+            breakpoints: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Breakpoints;
+
+    #[test]
+    fn get_test() {
+        let breakpoints = Breakpoints::new([(1200.0, 4), (0.0, 1), (600.0, 2)]);
+
+        assert_eq!(breakpoints.get(0.0), Some(&1));
+        assert_eq!(breakpoints.get(599.9), Some(&1));
+        assert_eq!(breakpoints.get(600.0), Some(&2));
+        assert_eq!(breakpoints.get(f32::INFINITY), Some(&4));
+
+        let breakpoints = Breakpoints::new([(300.0, 'a')]);
+        assert_eq!(breakpoints.get(200.0), None);
+        assert!(Breakpoints::<u8>::default().is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+//! Resizing the columns of a header by dragging the dividers between them.
+
+use iced::Point;
+
+/// The event produced while resizing the columns of a header.
+#[derive(Debug, Clone)]
+pub enum ResizeEvent {
+    /// The divider between two columns was moved.
+    ResizeColumn {
+        /// The name of the column left of the divider.
+        left_name: String,
+        /// The new width of the column left of the divider.
+        left_width: u16,
+        /// The name of the column right of the divider.
+        right_name: String,
+        /// The new width of the column right of the divider.
+        right_width: u16,
+    },
+    /// The divider was released.
+    Finished,
+}
+
+/// The state of the dividers between the columns of a header, tracking the
+/// divider that is hovered or dragged.
+#[derive(Clone, Debug, Default)]
+pub struct ResizeState {
+    /// Whether the cursor is hovering over the resize area of a divider.
+    pub resize_hovering: bool,
+
+    /// Whether a divider is being dragged.
+    pub resizing: bool,
+
+    /// The cursor position the dragging started at.
+    pub starting_cursor_pos: Option<Point>,
+
+    /// The width of the column left of the divider when the dragging started.
+    pub starting_left_width: f32,
+
+    /// The width of the column right of the divider when the dragging started.
+    pub starting_right_width: f32,
+
+    /// The index of the child left of the hovered or dragged divider.
+    pub resizing_idx: usize,
+}
+
+impl ResizeState {
+    /// Updates the hovered divider from the cursor position and the x
+    /// positions of the dividers, keeping the dragged one while resizing.
+    pub(crate) fn hover_dividers(&mut self, dividers: &[(usize, f32)], cursor_x: f32, leeway: f32) {
+        if self.resizing {
+            return;
+        }
+
+        let hovered = dividers
+            .iter()
+            .find(|(_, divider)| cursor_x > divider - leeway && cursor_x < divider + leeway);
+
+        self.resize_hovering = hovered.is_some();
+        if let Some((idx, _)) = hovered {
+            self.resizing_idx = *idx;
+        }
+    }
+
+    /// Starts dragging the hovered divider between the columns of the given widths.
+    pub(crate) fn start_resize(
+        &mut self,
+        cursor_position: Point,
+        left_width: f32,
+        right_width: f32,
+    ) {
+        self.resizing = true;
+        self.starting_cursor_pos = Some(cursor_position);
+        self.starting_left_width = left_width;
+        self.starting_right_width = right_width;
+    }
+
+    /// Stops dragging the divider, returning whether it was dragged.
+    pub(crate) fn finish_resize(&mut self) -> bool {
+        self.starting_cursor_pos = None;
+        std::mem::take(&mut self.resizing)
+    }
+
+    /// Returns the new widths of the columns left and right of the dragged
+    /// divider for the given cursor position, keeping their total width.
+    pub(crate) fn resized_widths(&self, cursor_x: f32, min_width: f32) -> (f32, f32) {
+        let delta = cursor_x - self.starting_cursor_pos.map_or(cursor_x, |pos| pos.x);
+        let total = self.starting_left_width + self.starting_right_width;
+        let left_width =
+            (self.starting_left_width + delta).clamp(min_width, (total - min_width).max(min_width));
+
+        (left_width, total - left_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResizeState;
+    use iced::Point;
+
+    #[test]
+    fn hover_dividers_test() {
+        let mut state = ResizeState::default();
+        let dividers = [(0, 100.0), (1, 250.0)];
+
+        state.hover_dividers(&dividers, 248.0, 5.0);
+        assert!(state.resize_hovering);
+        assert_eq!(state.resizing_idx, 1);
+
+        state.hover_dividers(&dividers, 180.0, 5.0);
+        assert!(!state.resize_hovering);
+
+        state.hover_dividers(&dividers, 103.0, 5.0);
+        state.start_resize(Point::new(103.0, 0.0), 100.0, 150.0);
+        state.hover_dividers(&dividers, 250.0, 5.0);
+        assert!(state.resize_hovering);
+        assert_eq!(state.resizing_idx, 0);
+    }
+
+    #[test]
+    fn resized_widths_test() {
+        let mut state = ResizeState::default();
+        state.start_resize(Point::new(100.0, 0.0), 100.0, 150.0);
+
+        assert_eq!(state.resized_widths(120.0, 30.0), (120.0, 130.0));
+        assert_eq!(state.resized_widths(0.0, 30.0), (30.0, 220.0));
+        assert_eq!(state.resized_widths(400.0, 30.0), (220.0, 30.0));
+
+        assert!(state.finish_resize());
+        assert!(!state.finish_resize());
+    }
+}
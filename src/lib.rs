@@ -16,6 +16,7 @@
 //! * `slide_bar` (Author: Andrew Wheeler <genusistimelord@gmail.com>)
 //! * `spinner` (Author: Iohann Rabeson <irabeson42@gmail.com>)
 //! * `tab_bar` (Author: Kaiden42 <gitlab@tinysn.com>)
+//! * `table`
 //! * `tabs` (Author: Kaiden42 <gitlab@tinysn.com>)
 //! * `time_picker` (Author: Kaiden42 <gitlab@tinysn.com>)
 //! * `typed_input` (Author: Ultraxime <36888699+Ultraxime@users.noreply.github.com>)
@@ -109,10 +110,16 @@ mod platform {
         grid::{Grid, GridRow},
     };
 
+    #[doc(no_inline)]
+    #[cfg(feature = "header")]
+    pub use {crate::widget::header, header::Header};
+
     #[doc(no_inline)]
     #[cfg(feature = "table")]
     pub use {
-        crate::style::TableHeaderStyles, crate::style::TableRowStyles, crate::widgets::table,
+        crate::widgets::table,
+        crate::widgets::table::SortDirection, crate::widgets::table::Table,
+        crate::widgets::table::TableColumn,
         crate::widgets::table::TableHeader, crate::widgets::table::TableHeaderState,
         crate::widgets::table::TableRow,
     };
@@ -18,17 +18,16 @@ pub mod color_picker;
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 
+#[cfg(feature = "header")]
+pub mod header;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 
 #[cfg(feature = "table")]
 pub mod table_row;
 #[cfg(feature = "table")]
-pub use table_row::TableRowStyles;
-#[cfg(feature = "table")]
 pub mod table_header;
-#[cfg(feature = "table")]
-pub use table_header::TableHeaderStyles;
 
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
@@ -0,0 +1,89 @@
+//! Displays a [`Header`](crate::widget::Header).
+//!
+//! *This API requires the following crate features to be activated: header*
+
+use super::{Status, StyleFn};
+use iced::{Background, Color, Theme};
+
+/// The style of a [`Header`](crate::widget::header::Header).
+#[derive(Clone, Copy, Debug)]
+pub struct Style {
+    /// The background of the [`Header`](crate::widget::header::Header).
+    pub background: Option<Background>,
+
+    /// The border radius of the [`Header`](crate::widget::header::Header).
+    pub border_radius: f32,
+
+    /// The border width of the [`Header`](crate::widget::header::Header).
+    pub border_width: f32,
+
+    /// The border color of the [`Header`](crate::widget::header::Header).
+    pub border_color: Color,
+
+    /// The default text color of the columns of the
+    /// [`Header`](crate::widget::header::Header).
+    pub text_color: Color,
+
+    /// The color of the divider between two columns that is hovered or dragged.
+    pub divider_color: Color,
+}
+
+/// The Catalog of a [`Header`](crate::widget::header::Header).
+pub trait Catalog {
+    ///Style for the trait to use.
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    ///
+    /// The status is [`Status::Hovered`] while the cursor is over a divider
+    /// between two columns and [`Status::Pressed`] while it is dragged.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::BLACK,
+            divider_color: [0.5, 0.5, 0.5].into(),
+        }
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self, Style>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(primary)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The primary theme of a [`Header`](crate::widget::header::Header).
+#[must_use]
+pub fn primary(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let base = Style {
+        background: Some(Background::Color(palette.background.weak.color)),
+        text_color: palette.background.weak.text,
+        divider_color: palette.primary.base.color,
+        ..Style::default()
+    };
+
+    match status {
+        Status::Pressed => Style {
+            divider_color: palette.primary.strong.color,
+            ..base
+        },
+        _ => base,
+    }
+}
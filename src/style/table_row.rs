@@ -1,18 +1,14 @@
 //! Table Row Styling
 //!
-//! *This API requires the following crate features to be activated: `table`*;
-//!
-
-use std::rc::Rc;
+//! *This API requires the following crate features to be activated: `table`*
 
-use iced::{border::Radius, Background, Color, Theme};
+use super::Status;
+use iced::{border::Radius, theme::palette::Extended, Background, Color, Theme};
 
-/// The appearance of a [`TableRow`](crate::native::table::TableRow) Row itself or
+/// The style of a [`TableRow`](crate::widget::table::TableRow) Row itself or
 /// individual cell.
-///
-
 #[derive(Clone, Copy, Debug)]
-pub struct RowOrCellAppearance {
+pub struct RowOrCellStyle {
     /// The table row's background.
     pub background: Option<Background>,
 
@@ -35,7 +31,7 @@ pub struct RowOrCellAppearance {
     pub offset_right: f32,
 }
 
-impl Default for RowOrCellAppearance {
+impl Default for RowOrCellStyle {
     fn default() -> Self {
         Self {
             text_color: [1.0, 1.0, 1.0].into(),
@@ -49,256 +45,258 @@ impl Default for RowOrCellAppearance {
     }
 }
 
-/// The appearance of a [`TableRow`](crate::native::table::TableRow)
-/// containing separate appearances for the entire row and individual cells.
-#[derive(Clone, Copy, Debug)]
-pub struct Appearance {
-    /// Appearance of an entire row
-    pub row: RowOrCellAppearance,
-    /// Appearance of an individual cell
-    pub cell: RowOrCellAppearance,
+/// The style of a [`TableRow`](crate::widget::table::TableRow)
+/// containing separate styles for the entire row and individual cells.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Style {
+    /// Style of an entire row
+    pub row: RowOrCellStyle,
+    /// Style of an individual cell
+    pub cell: RowOrCellStyle,
+    /// The background behind the rows of a table, drawn below the rows of
+    /// frozen columns so that the scrolled cells do not show through them.
+    pub background: Option<Background>,
 }
 
-impl Default for Appearance {
-    fn default() -> Self {
-        Self {
-            row: RowOrCellAppearance::default(),
-            cell: RowOrCellAppearance::default(),
-        }
+/// The style function of a [`TableRow`](crate::widget::table::TableRow),
+/// which also receives the position of the row in its table.
+pub type RowStyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status, usize) -> Style + 'a>;
+
+/// The Catalog of a [`TableRow`](crate::widget::table::TableRow).
+pub trait Catalog {
+    ///Style for the trait to use.
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status, for the row at
+    /// `row_index` in its table.
+    ///
+    /// Hovered rows have the [`Status::Hovered`] status and selected rows,
+    /// hovered or not, the [`Status::Selected`] one.
+    fn style(&self, class: &Self::Class<'_>, status: Status, row_index: usize) -> Style;
+}
+
+impl Catalog for Theme {
+    type Class<'a> = RowStyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(primary)
     }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status, row_index: usize) -> Style {
+        class(self, status, row_index)
+    }
+}
+
+/// The primary theme of a [`TableRow`](crate::widget::table::TableRow).
+#[must_use]
+pub fn primary(theme: &Theme, status: Status, _row_index: usize) -> Style {
+    let palette = theme.extended_palette();
+    let row = RowOrCellStyle {
+        text_color: palette.primary.strong.color,
+        background: Some(Background::Color(palette.primary.base.color)),
+        ..RowOrCellStyle::default()
+    };
+    let base = styled(
+        palette,
+        row,
+        RowOrCellStyle {
+            border_width: 1.0,
+            border_color: Color::BLACK,
+            ..row
+        },
+    );
+
+    with_status(palette, &base, status, 0.60)
+}
+
+/// The alternate theme of a [`TableRow`](crate::widget::table::TableRow),
+/// with a lighter background.
+#[must_use]
+pub fn alternate(theme: &Theme, status: Status, _row_index: usize) -> Style {
+    let palette = theme.extended_palette();
+    let row = RowOrCellStyle {
+        text_color: palette.primary.strong.color,
+        background: Some(Background::Color(Color {
+            a: 0.50,
+            ..palette.primary.base.color
+        })),
+        ..RowOrCellStyle::default()
+    };
+
+    with_status(palette, &styled(palette, row, row), status, 0.25)
+}
+
+/// The highlight theme of a [`TableRow`](crate::widget::table::TableRow).
+#[must_use]
+pub fn highlight(theme: &Theme, status: Status, _row_index: usize) -> Style {
+    let palette = theme.extended_palette();
+    let row = RowOrCellStyle {
+        text_color: palette.primary.strong.color,
+        background: Some(Background::Color(Color {
+            a: 0.30,
+            ..palette.primary.base.color
+        })),
+        ..RowOrCellStyle::default()
+    };
+
+    with_status(palette, &styled(palette, row, row), status, 0.60)
+}
+
+/// The lowlight theme of a [`TableRow`](crate::widget::table::TableRow),
+/// without a background.
+#[must_use]
+pub fn lowlight(theme: &Theme, status: Status, _row_index: usize) -> Style {
+    let palette = theme.extended_palette();
+    let row = RowOrCellStyle {
+        text_color: palette.primary.base.color,
+        background: Some(Background::Color(Color::TRANSPARENT)),
+        ..RowOrCellStyle::default()
+    };
+
+    with_status(palette, &styled(palette, row, row), status, 0.60)
 }
 
-/// A set of rules that dictate the style of a table row.
-pub trait StyleSheet {
-    /// Style for the trait to use.
-    type Style: Default;
+/// The selected theme of a [`TableRow`](crate::widget::table::TableRow),
+/// drawing every row like the primary background without cell borders.
+#[must_use]
+pub fn selected(theme: &Theme, status: Status, _row_index: usize) -> Style {
+    let palette = theme.extended_palette();
+    let row = RowOrCellStyle {
+        text_color: palette.primary.strong.color,
+        background: Some(Background::Color(palette.primary.base.color)),
+        ..RowOrCellStyle::default()
+    };
+
+    with_status(palette, &styled(palette, row, row), status, 0.60)
+}
 
-    /// The default appearance of a table row.
-    fn appearance(&self, style: &Self::Style, row_id: u16) -> Appearance;
+/// The striped theme of a [`TableRow`](crate::widget::table::TableRow), with
+/// alternating backgrounds for even and odd rows.
+#[must_use]
+pub fn striped(theme: &Theme, status: Status, row_index: usize) -> Style {
+    let palette = theme.extended_palette();
+    let background = if row_index % 2 == 0 {
+        palette.background.base.color
+    } else {
+        palette.background.weak.color
+    };
+    let row = RowOrCellStyle {
+        text_color: palette.background.base.text,
+        background: Some(Background::Color(background)),
+        ..RowOrCellStyle::default()
+    };
 
-    /// The appearance of a table row when it is hovered over.
-    fn hovered(&self, style: &Self::Style, row_id: u16) -> Appearance;
+    with_weak_hover(palette, &styled(palette, row, row), status)
 }
 
-#[derive(Clone, Default)]
-#[allow(missing_docs, clippy::missing_docs_in_private_items)]
-/// Default Prebuilt ``TableRow`` Styles
-pub enum TableRowStyles {
-    #[default]
-    Default,
-    TableRowAlternate,
-    TableRowHighlight,
-    TableRowLowlight,
-    TableRowSelected,
-    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+/// The bordered theme of a [`TableRow`](crate::widget::table::TableRow), with
+/// grid lines between the cells.
+#[must_use]
+pub fn bordered(theme: &Theme, status: Status, _row_index: usize) -> Style {
+    let palette = theme.extended_palette();
+    let row = RowOrCellStyle {
+        text_color: palette.background.base.text,
+        background: Some(Background::Color(palette.background.base.color)),
+        ..RowOrCellStyle::default()
+    };
+    let base = styled(
+        palette,
+        row,
+        RowOrCellStyle {
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+            ..row
+        },
+    );
+
+    with_weak_hover(palette, &base, status)
 }
 
-impl TableRowStyles {
-    /// Creates a custom [`TableRowStyles`] style variant.
-    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
-        Self::Custom(Rc::new(style_sheet))
+fn styled(palette: &Extended, row: RowOrCellStyle, cell: RowOrCellStyle) -> Style {
+    Style {
+        row,
+        cell,
+        background: Some(Background::Color(palette.background.base.color)),
     }
 }
 
-impl StyleSheet for Theme {
-    type Style = TableRowStyles;
-
-    fn appearance(&self, style: &Self::Style, row_id: u16) -> Appearance {
-        let palette = self.extended_palette();
-
-        match style {
-            TableRowStyles::Default => Appearance {
-                row: RowOrCellAppearance {
-                    text_color: palette.primary.strong.color,
-                    background: Some(Background::Color(palette.primary.base.color)),
-                    border_radius: 0.0.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                    offset_left: 0.0,
-                    offset_right: 0.0,
-                },
-                cell: RowOrCellAppearance {
-                    text_color: palette.primary.strong.color,
-                    background: Some(Background::Color(palette.primary.base.color)),
-                    border_radius: 0.0.into(),
-                    border_width: 1.0,
-                    border_color: Color::BLACK,
-                    offset_left: 0.0,
-                    offset_right: 0.0,
-                },
-            },
-            TableRowStyles::TableRowAlternate => Appearance {
-                row: RowOrCellAppearance {
-                    text_color: palette.primary.strong.color,
-                    background: Some(Background::Color(Color {
-                        a: 0.50,
-                        ..palette.primary.base.color
-                    })),
-                    ..RowOrCellAppearance::default()
-                },
-                cell: RowOrCellAppearance {
-                    text_color: palette.primary.strong.color,
-                    background: Some(Background::Color(Color {
-                        a: 0.50,
-                        ..palette.primary.base.color
-                    })),
-                    ..RowOrCellAppearance::default()
-                },
-            },
-            TableRowStyles::TableRowHighlight => Appearance {
-                row: RowOrCellAppearance {
-                    text_color: palette.primary.strong.color,
-                    background: Some(Background::Color(Color {
-                        a: 0.30,
-                        ..palette.primary.base.color
-                    })),
-                    border_radius: 0.0.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                    offset_left: 0.0,
-                    offset_right: 0.0,
-                },
-                cell: RowOrCellAppearance {
-                    text_color: palette.primary.strong.color,
-                    background: Some(Background::Color(Color {
-                        a: 0.30,
-                        ..palette.primary.base.color
-                    })),
-                    border_radius: 0.0.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                    offset_left: 0.0,
-                    offset_right: 0.0,
-                },
-            },
-            TableRowStyles::TableRowLowlight => Appearance {
-                row: RowOrCellAppearance {
-                    text_color: palette.primary.base.color,
-                    background: Some(Background::Color(Color::TRANSPARENT)),
-                    border_radius: 0.0.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                    offset_left: 0.0,
-                    offset_right: 0.0,
-                },
-                cell: RowOrCellAppearance {
-                    text_color: palette.primary.base.color,
-                    background: Some(Background::Color(Color::TRANSPARENT)),
-                    border_radius: 0.0.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                    offset_left: 0.0,
-                    offset_right: 0.0,
-                },
-            },
-            TableRowStyles::TableRowSelected => Appearance {
-                row: RowOrCellAppearance {
-                    text_color: palette.primary.strong.color,
-                    background: Some(Background::Color(palette.primary.base.color)),
-                    border_radius: 0.0.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                    offset_left: 0.0,
-                    offset_right: 0.0,
+/// Applies the status to the style, hovering with the primary color at the
+/// given opacity.
+fn with_status(palette: &Extended, style: &Style, status: Status, hover_alpha: f32) -> Style {
+    match status {
+        Status::Hovered => {
+            let background = Some(Background::Color(Color {
+                a: hover_alpha,
+                ..palette.primary.base.color
+            }));
+
+            Style {
+                row: RowOrCellStyle {
+                    background,
+                    ..style.row
                 },
-                cell: RowOrCellAppearance {
-                    text_color: palette.primary.strong.color,
-                    background: Some(Background::Color(palette.primary.base.color)),
-                    border_radius: 0.0.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                    offset_left: 0.0,
-                    offset_right: 0.0,
+                cell: RowOrCellStyle {
+                    background,
+                    ..style.cell
                 },
-            },
-            TableRowStyles::Custom(custom) => return custom.appearance(self, row_id),
+                ..*style
+            }
         }
+        Status::Selected => selection(palette, style),
+        _ => *style,
     }
+}
 
-    fn hovered(&self, style: &Self::Style, row_id: u16) -> Appearance {
-        let palette = self.extended_palette();
-        match style {
-            TableRowStyles::Default => Appearance {
-                row: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.60,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).row
-                },
-                cell: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.60,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).cell
-                },
-            },
-            TableRowStyles::TableRowAlternate => Appearance {
-                row: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.25,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).row
-                },
-                cell: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.25,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).cell
-                },
-            },
-            TableRowStyles::TableRowHighlight => Appearance {
-                row: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.60,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).row
-                },
-                cell: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.60,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).cell
-                },
-            },
-            TableRowStyles::TableRowLowlight => Appearance {
-                row: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.60,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).row
-                },
-                cell: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.60,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).cell
-                },
-            },
-            TableRowStyles::TableRowSelected => Appearance {
-                row: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.60,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).row
+/// Applies the status to the style, hovering with the weak primary color.
+fn with_weak_hover(palette: &Extended, style: &Style, status: Status) -> Style {
+    match status {
+        Status::Hovered => {
+            let background = Some(Background::Color(palette.primary.weak.color));
+            let text_color = palette.primary.weak.text;
+
+            Style {
+                row: RowOrCellStyle {
+                    background,
+                    text_color,
+                    ..style.row
                 },
-                cell: RowOrCellAppearance {
-                    background: Some(Background::Color(Color {
-                        a: 0.60,
-                        ..palette.primary.base.color
-                    })),
-                    ..self.appearance(style, row_id).cell
+                cell: RowOrCellStyle {
+                    background,
+                    text_color,
+                    ..style.cell
                 },
-            },
-            TableRowStyles::Custom(custom) => custom.hovered(self, row_id),
+                ..*style
+            }
         }
+        Status::Selected => selection(palette, style),
+        _ => *style,
+    }
+}
+
+/// The style of a selected row, keeping the borders of the given style.
+fn selection(palette: &Extended, style: &Style) -> Style {
+    let selected = RowOrCellStyle {
+        text_color: palette.primary.strong.text,
+        background: Some(Background::Color(palette.primary.strong.color)),
+        ..RowOrCellStyle::default()
+    };
+
+    Style {
+        row: RowOrCellStyle {
+            border_radius: style.row.border_radius,
+            border_width: style.row.border_width,
+            border_color: style.row.border_color,
+            ..selected
+        },
+        cell: RowOrCellStyle {
+            border_radius: style.cell.border_radius,
+            border_width: style.cell.border_width,
+            border_color: style.cell.border_color,
+            ..selected
+        },
+        ..*style
     }
 }
@@ -50,6 +50,11 @@ pub mod grid;
 #[cfg(feature = "grid")]
 pub use grid::{Grid, GridRow};
 
+#[cfg(feature = "header")]
+pub mod header;
+#[cfg(feature = "header")]
+pub use header::Header;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 #[cfg(feature = "tab_bar")]
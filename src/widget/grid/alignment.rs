@@ -0,0 +1,58 @@
+use iced::alignment::{Horizontal, Vertical};
+
+/// The alignment of the widgets of a column, a row or a single cell of a
+/// [`Grid`](super::Grid), overriding the alignment of the grid.
+///
+/// An unset axis keeps the alignment of the next less specific level: a cell
+/// falls back to its row, a row to its column and a column to the grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CellAlignment {
+    /// The horizontal alignment, if overridden.
+    pub horizontal: Option<Horizontal>,
+    /// The vertical alignment, if overridden.
+    pub vertical: Option<Vertical>,
+}
+
+impl CellAlignment {
+    /// Creates a [`CellAlignment`] overriding both axes.
+    #[must_use]
+    pub const fn new(horizontal: Horizontal, vertical: Vertical) -> Self {
+        Self {
+            horizontal: Some(horizontal),
+            vertical: Some(vertical),
+        }
+    }
+
+    /// Returns the alignment with the unset axes taken from `fallback`.
+    #[must_use]
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            horizontal: self.horizontal.or(fallback.horizontal),
+            vertical: self.vertical.or(fallback.vertical),
+        }
+    }
+}
+
+impl From<Horizontal> for CellAlignment {
+    fn from(horizontal: Horizontal) -> Self {
+        Self {
+            horizontal: Some(horizontal),
+            vertical: None,
+        }
+    }
+}
+
+impl From<Vertical> for CellAlignment {
+    fn from(vertical: Vertical) -> Self {
+        Self {
+            horizontal: None,
+            vertical: Some(vertical),
+        }
+    }
+}
+
+impl From<(Horizontal, Vertical)> for CellAlignment {
+    fn from((horizontal, vertical): (Horizontal, Vertical)) -> Self {
+        Self::new(horizontal, vertical)
+    }
+}
@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashSet};
 
 use iced::{
     advanced::{
@@ -7,50 +7,52 @@ use iced::{
         widget::Tree,
     },
     alignment::{Horizontal, Vertical},
-    Length, Padding, Pixels, Point, Size,
+    Element, Length, Padding, Pixels, Point, Size,
 };
-use itertools::{Itertools, Position};
+use itertools::Itertools;
 
-use super::types::GridRow;
+use super::{track::Track, types::Span};
+
+/// The cell an element of a grid is placed in and the tracks it spans.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Placement {
+    pub(super) row: usize,
+    pub(super) column: usize,
+    pub(super) span: Span,
+}
 
 #[allow(clippy::too_many_arguments)]
 pub(super) fn layout<Message, Theme, Renderer>(
     tree: &mut Tree,
     renderer: &Renderer,
     limits: &Limits,
-    column_count: usize,
-    row_count: usize,
-    element_count: usize,
-    rows: &[GridRow<'_, Message, Theme, Renderer>],
+    elements: &[&Element<'_, Message, Theme, Renderer>],
+    placements: &[Placement],
     column_spacing: Pixels,
     row_spacing: Pixels,
     padding: Padding,
-    horizontal_alignment: Horizontal,
-    vertical_alignment: Vertical,
+    alignments: &[(Horizontal, Vertical)],
     width: Length,
     height: Length,
-    column_lengths: &[Length],
-    row_lengths: &[Length],
+    column_tracks: &[Track],
+    row_tracks: &[Track],
 ) -> Node
 where
     Renderer: renderer::Renderer,
 {
-    let mut column_widths = Vec::<f32>::with_capacity(column_count);
-    let mut row_heights = Vec::<f32>::with_capacity(row_count);
     let grid_limit = limits.shrink(padding);
     // Measure the minimum row and column size to fit the contents
-    minimum_row_column_sizes(
-        tree,
-        renderer,
-        &mut column_widths,
-        &mut row_heights,
-        rows,
+    let (mut column_widths, mut row_heights) = minimum_row_column_sizes(
+        &measure(tree, renderer, elements),
+        placements,
+        column_spacing,
+        row_spacing,
         grid_limit.max(),
     );
 
     // Adjust for fixed row and column sizes
-    adjust_size_for_fixed_length(&mut column_widths, column_lengths);
-    adjust_size_for_fixed_length(&mut row_heights, row_lengths);
+    adjust_size_for_track_minimum(&mut column_widths, column_tracks);
+    adjust_size_for_track_minimum(&mut row_heights, row_tracks);
 
     // Calculate grid limits
     let min_size = Size::new(
@@ -69,21 +71,20 @@ where
     let grid_size = grid_limits.resolve(width, height, grid_size);
 
     // Allocate the available space
-    let available_width = grid_size.width - total_spacing(column_count, column_spacing);
-    let available_height = grid_size.height - total_spacing(row_count, row_spacing);
-    allocate_space(&mut column_widths, column_lengths, available_width);
-    allocate_space(&mut row_heights, row_lengths, available_height);
+    let available_width = grid_size.width - total_spacing(column_widths.len(), column_spacing);
+    let available_height = grid_size.height - total_spacing(row_heights.len(), row_spacing);
+    allocate_space(&mut column_widths, column_tracks, available_width);
+    allocate_space(&mut row_heights, row_tracks, available_height);
 
     // Lay out the widget
     create_grid_layout(
         tree,
-        element_count,
-        rows,
+        elements,
+        placements,
         &row_heights,
         &column_widths,
         renderer,
-        horizontal_alignment,
-        vertical_alignment,
+        alignments,
         column_spacing,
         row_spacing,
         padding,
@@ -91,60 +92,203 @@ where
     )
 }
 
-fn minimum_row_column_sizes<Message, Theme, Renderer>(
+/// Places the elements of the rows with the given spans in the next free
+/// cells of their row, skipping the cells covered by elements of earlier rows
+/// and by the `reserved` placements.
+pub(super) fn place<'s>(
+    rows: impl IntoIterator<Item = &'s [Span]>,
+    reserved: &[Placement],
+) -> Vec<Placement> {
+    let mut covered = covered_cells(reserved);
+    let mut placements = Vec::new();
+
+    for (row, spans) in rows.into_iter().enumerate() {
+        let mut column = 0;
+
+        for &span in spans {
+            while covered.contains(&(row, column)) {
+                column += 1;
+            }
+
+            let placement = Placement { row, column, span };
+            cover(&mut covered, &placement);
+            placements.push(placement);
+            column += span.columns;
+        }
+    }
+
+    placements
+}
+
+/// Places the elements with the given spans one after the other in the next
+/// free cells of the given number of columns, narrowing wider spans to fit
+/// and skipping the cells covered by the `reserved` placements.
+pub(super) fn flow(
+    spans: impl IntoIterator<Item = Span>,
+    columns: usize,
+    reserved: &[Placement],
+) -> Vec<Placement> {
+    let columns = columns.max(1);
+    let mut covered = covered_cells(reserved);
+    let (mut row, mut column) = (0, 0);
+
+    spans
+        .into_iter()
+        .map(|span| {
+            let span = Span {
+                columns: span.columns.min(columns),
+                ..span
+            };
+
+            while column + span.columns > columns
+                || (column..column + span.columns).any(|c| covered.contains(&(row, c)))
+            {
+                column += 1;
+                if column + span.columns > columns {
+                    row += 1;
+                    column = 0;
+                }
+            }
+
+            let placement = Placement { row, column, span };
+            cover(&mut covered, &placement);
+            column += span.columns;
+            placement
+        })
+        .collect()
+}
+
+/// Returns the cells covered by the given placements.
+fn covered_cells(placements: &[Placement]) -> HashSet<(usize, usize)> {
+    let mut covered = HashSet::new();
+    for placement in placements {
+        cover(&mut covered, placement);
+    }
+    covered
+}
+
+/// Adds the cells covered by the placement to the `covered` cells.
+fn cover(covered: &mut HashSet<(usize, usize)>, placement: &Placement) {
+    for row in placement.row..placement.row + placement.span.rows {
+        for column in placement.column..placement.column + placement.span.columns {
+            let _ = covered.insert((row, column));
+        }
+    }
+}
+
+/// Measures the size every element needs to fit its contents.
+fn measure<Message, Theme, Renderer>(
     tree: &mut Tree,
     renderer: &Renderer,
-    column_widths: &mut Vec<f32>,
-    row_heights: &mut Vec<f32>,
-    rows: &[GridRow<'_, Message, Theme, Renderer>],
-    max_allowed_size: Size<f32>,
-) where
+    elements: &[&Element<'_, Message, Theme, Renderer>],
+) -> Vec<Size>
+where
     Renderer: renderer::Renderer,
 {
-    let mut children = tree.children.iter_mut();
-    for row in rows {
-        let mut row_height = 0.0f32;
-        let mut min_width = 0.0f32;
-
-        for (col_idx, element) in row.elements.iter().enumerate() {
-            let child_limits = Limits::NONE.width(Length::Shrink).height(Length::Shrink);
-            let Size { width, height } = element
+    let child_limits = Limits::NONE.width(Length::Shrink).height(Length::Shrink);
+
+    elements
+        .iter()
+        .zip(&mut tree.children)
+        .map(|(element, tree)| {
+            element
                 .as_widget()
-                .layout(
-                    children.next().expect("grid missing expected child"),
-                    renderer,
-                    &child_limits,
-                )
-                .size();
-
-            min_width = if width == f32::INFINITY {
-                min_width
-            } else {
-                min_width.max(width)
-            };
+                .layout(tree, renderer, &child_limits)
+                .size()
+        })
+        .collect()
+}
 
-            #[allow(clippy::option_if_let_else)]
-            match column_widths.get_mut(col_idx) {
-                Some(col_width) => {
-                    *col_width = max_allowed_size.width.min(col_width.max(min_width));
-                }
-                None => column_widths.insert(col_idx, max_allowed_size.width.min(min_width)),
-            }
+/// Returns the minimum column widths and row heights fitting the elements
+/// of the given sizes at their placements.
+///
+/// The tracks first fit the elements spanning a single track. An element
+/// spanning several tracks then evenly grows them if it does not fit.
+fn minimum_row_column_sizes(
+    sizes: &[Size],
+    placements: &[Placement],
+    column_spacing: Pixels,
+    row_spacing: Pixels,
+    max_allowed_size: Size<f32>,
+) -> (Vec<f32>, Vec<f32>) {
+    let column_count = placements
+        .iter()
+        .map(|placement| placement.column + placement.span.columns)
+        .max()
+        .unwrap_or(0);
+    let row_count = placements
+        .iter()
+        .map(|placement| placement.row + placement.span.rows)
+        .max()
+        .unwrap_or(0);
+    let mut column_widths = vec![0.0f32; column_count];
+    let mut row_heights = vec![0.0f32; row_count];
+
+    let min_width = |size: &Size| {
+        if size.width == f32::INFINITY {
+            0.0
+        } else {
+            size.width.min(max_allowed_size.width)
+        }
+    };
 
-            row_height = row_height.max(height);
+    for (size, placement) in sizes.iter().zip(placements) {
+        if placement.span.columns == 1 {
+            column_widths[placement.column] = column_widths[placement.column].max(min_width(size));
         }
-        row_heights.push(row_height);
+        if placement.span.rows == 1 {
+            row_heights[placement.row] = row_heights[placement.row].max(size.height);
+        }
+    }
+
+    for (size, placement) in sizes
+        .iter()
+        .zip(placements)
+        .sorted_by_key(|(_, placement)| placement.span.columns)
+        .filter(|(_, placement)| placement.span.columns > 1)
+    {
+        grow_to_fit(
+            &mut column_widths[placement.column..placement.column + placement.span.columns],
+            min_width(size),
+            column_spacing,
+        );
+    }
+
+    for (size, placement) in sizes
+        .iter()
+        .zip(placements)
+        .sorted_by_key(|(_, placement)| placement.span.rows)
+        .filter(|(_, placement)| placement.span.rows > 1)
+    {
+        grow_to_fit(
+            &mut row_heights[placement.row..placement.row + placement.span.rows],
+            size.height,
+            row_spacing,
+        );
     }
+
+    (column_widths, row_heights)
 }
 
-fn adjust_size_for_fixed_length(sizes: &mut [f32], length_settings: &[Length]) {
-    for (size, lenght) in sizes.iter_mut().zip(length_settings.iter().cycle()) {
-        if let Length::Fixed(value) = *lenght {
-            *size = size.max(value);
+/// Evenly grows the spanned tracks so that, with the spacing between them,
+/// they are at least `needed` long.
+fn grow_to_fit(tracks: &mut [f32], needed: f32, spacing: Pixels) {
+    let missing = needed - total_length(tracks, spacing);
+
+    if missing > 0.0 {
+        let extra = missing / tracks.len() as f32;
+        for track in tracks {
+            *track += extra;
         }
     }
 }
 
+fn adjust_size_for_track_minimum(sizes: &mut [f32], tracks: &[Track]) {
+    for (size, track) in sizes.iter_mut().zip(tracks.iter().cycle()) {
+        *size = track.base_size(*size);
+    }
+}
+
 fn total_length(element_sizes: &[f32], spacing: Pixels) -> f32 {
     let n_elements = element_sizes.len();
     element_sizes.iter().sum::<f32>() + total_spacing(n_elements, spacing)
@@ -154,15 +298,17 @@ fn total_spacing(element_count: usize, spacing: Pixels) -> f32 {
     element_count.saturating_sub(1) as f32 * spacing.0
 }
 
-fn allocate_space(current_sizes: &mut [f32], length_settings: &[Length], available_space: f32) {
-    let mut fill_factor_sum = length_settings
+fn allocate_space(current_sizes: &mut [f32], tracks: &[Track], available_space: f32) {
+    grow_limited_tracks(current_sizes, tracks, available_space);
+
+    let mut fraction_sum = tracks
         .iter()
         .cycle()
         .take(current_sizes.len())
-        .map(Length::fill_factor)
-        .sum::<u16>();
+        .map(|track| track.fraction())
+        .sum::<f32>();
 
-    if fill_factor_sum == 0 {
+    if fraction_sum <= 0.0 {
         return;
     }
 
@@ -170,38 +316,71 @@ fn allocate_space(current_sizes: &mut [f32], length_settings: &[Length], availab
 
     let sorted_iter = current_sizes
         .iter_mut()
-        .zip(length_settings.iter().cycle())
-        .sorted_by(|(&mut a_size, &a_length), (&mut b_size, &b_length)| {
-            if a_length.fill_factor() == 0 {
+        .zip(tracks.iter().cycle())
+        .sorted_by(|(&mut a_size, a_track), (&mut b_size, b_track)| {
+            if a_track.fraction() <= 0.0 {
                 return Ordering::Less;
-            } else if b_length.fill_factor() == 0 {
+            } else if b_track.fraction() <= 0.0 {
                 return Ordering::Greater;
             }
 
-            (b_size / f32::from(b_length.fill_factor()))
-                .total_cmp(&(a_size / f32::from(a_length.fill_factor())))
+            (b_size / b_track.fraction()).total_cmp(&(a_size / a_track.fraction()))
         });
 
-    for (size, length) in sorted_iter {
-        let fill_factor = length.fill_factor();
-        let fill_size = f32::from(fill_factor) / f32::from(fill_factor_sum) * space_to_divide;
+    for (size, track) in sorted_iter {
+        let fraction = track.fraction();
+        let fill_size = if fraction > 0.0 {
+            fraction / fraction_sum * space_to_divide
+        } else {
+            0.0
+        };
         let new_size = size.max(fill_size);
-        fill_factor_sum -= fill_factor;
+        fraction_sum -= fraction;
         space_to_divide -= new_size;
         *size = new_size;
     }
 }
 
+/// Evenly grows the tracks with a fixed maximum towards it, as far as the
+/// available space allows.
+fn grow_limited_tracks(sizes: &mut [f32], tracks: &[Track], available_space: f32) {
+    let limits = sizes
+        .iter()
+        .zip(tracks.iter().cycle())
+        .map(|(&size, track)| track.growth_limit(size))
+        .collect::<Vec<_>>();
+
+    // Every round either distributes all free space or lets a track reach its limit.
+    for _ in 0..sizes.len() {
+        let free_space = available_space - sizes.iter().sum::<f32>();
+        let growing = sizes
+            .iter()
+            .zip(&limits)
+            .filter(|(size, limit)| size < limit)
+            .count();
+
+        if free_space <= 0.0 || growing == 0 {
+            return;
+        }
+
+        let share = free_space / growing as f32;
+        for (size, &limit) in sizes.iter_mut().zip(&limits) {
+            if *size < limit {
+                *size = (*size + share).min(limit);
+            }
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn create_grid_layout<Message, Theme, Renderer>(
     tree: &mut Tree,
-    element_count: usize,
-    rows: &[GridRow<'_, Message, Theme, Renderer>],
+    elements: &[&Element<'_, Message, Theme, Renderer>],
+    placements: &[Placement],
     row_heights: &[f32],
     column_widths: &[f32],
     renderer: &Renderer,
-    horizontal_alignment: Horizontal,
-    vertical_alignment: Vertical,
+    alignments: &[(Horizontal, Vertical)],
     column_spacing: Pixels,
     row_spacing: Pixels,
     padding: Padding,
@@ -210,51 +389,154 @@ fn create_grid_layout<Message, Theme, Renderer>(
 where
     Renderer: renderer::Renderer,
 {
-    let mut y = padding.top;
-    let mut nodes = Vec::with_capacity(element_count);
-    let mut children = tree.children.iter_mut();
-
-    for (row_position, (row, &row_height)) in rows.iter().zip(row_heights).with_position() {
-        let mut x = padding.left;
-        for (col_position, (element, &column_width)) in
-            row.elements.iter().zip(column_widths).with_position()
-        {
-            let widget = element.as_widget();
-            let widget_size = widget.size();
-            let widget_limits = Limits::NONE
-                .width(widget_size.width)
-                .height(widget_size.height)
-                .max_width(column_width)
-                .max_height(row_height);
-
-            let node = widget
-                .layout(
-                    children.next().expect("Grid missing child"),
-                    renderer,
-                    &widget_limits,
-                )
-                .move_to(Point::new(x, y))
-                .align(
-                    horizontal_alignment.into(),
-                    vertical_alignment.into(),
-                    Size::new(column_width, row_height),
+    let column_offsets = offsets(column_widths, padding.left, column_spacing);
+    let row_offsets = offsets(row_heights, padding.top, row_spacing);
+
+    let nodes = elements
+        .iter()
+        .zip(&mut tree.children)
+        .zip(placements)
+        .zip(alignments)
+        .map(
+            |(((element, tree), placement), &(horizontal_alignment, vertical_alignment))| {
+                let columns = placement.column..placement.column + placement.span.columns;
+                let rows = placement.row..placement.row + placement.span.rows;
+                let cell_size = Size::new(
+                    total_length(&column_widths[columns], column_spacing),
+                    total_length(&row_heights[rows], row_spacing),
                 );
-            nodes.push(node);
 
-            x += column_width;
-            if not_last(col_position) {
-                x += column_spacing.0;
-            }
-        }
-        y += row_height;
-        if not_last(row_position) {
-            y += row_spacing.0;
-        }
-    }
+                let widget = element.as_widget();
+                let widget_size = widget.size();
+                let widget_limits = Limits::NONE
+                    .width(widget_size.width)
+                    .height(widget_size.height)
+                    .max_width(cell_size.width)
+                    .max_height(cell_size.height);
+
+                widget
+                    .layout(tree, renderer, &widget_limits)
+                    .move_to(Point::new(
+                        column_offsets[placement.column],
+                        row_offsets[placement.row],
+                    ))
+                    .align(
+                        horizontal_alignment.into(),
+                        vertical_alignment.into(),
+                        cell_size,
+                    )
+            },
+        )
+        .collect();
 
     Node::with_children(grid_size, nodes)
 }
 
-fn not_last(position: Position) -> bool {
-    position != Position::Last && position != Position::Only
+/// Returns the position of every track, starting at `start`.
+fn offsets(sizes: &[f32], start: f32, spacing: Pixels) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(start, |position, size| {
+            let offset = *position;
+            *position += size + spacing.0;
+            Some(offset)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{allocate_space, flow, grow_to_fit, place, Placement};
+    use crate::widget::grid::{
+        track::{Breadth, Track},
+        types::Span,
+    };
+
+    fn span(rows: usize, columns: usize) -> Span {
+        Span { rows, columns }
+    }
+
+    #[test]
+    fn place_test() {
+        let rows: [&[Span]; 3] = [
+            &[span(2, 1), Span::ONE, Span::ONE],
+            &[Span::ONE, span(1, 2)],
+            &[span(1, 3)],
+        ];
+        let cells = place(rows, &[])
+            .into_iter()
+            .map(|Placement { row, column, .. }| (row, column))
+            .collect::<Vec<_>>();
+
+        assert_eq!(cells, [(0, 0), (0, 1), (0, 2), (1, 1), (1, 2), (2, 0)]);
+
+        let reserved = [Placement {
+            row: 0,
+            column: 0,
+            span: span(2, 1),
+        }];
+        let rows: [&[Span]; 2] = [&[Span::ONE, Span::ONE], &[Span::ONE]];
+        let cells = place(rows, &reserved)
+            .into_iter()
+            .map(|Placement { row, column, .. }| (row, column))
+            .collect::<Vec<_>>();
+
+        assert_eq!(cells, [(0, 1), (0, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn flow_test() {
+        let spans = [span(2, 1), Span::ONE, span(1, 2), Span::ONE, span(1, 3)];
+        let cells = |columns| {
+            flow(spans, columns, &[])
+                .into_iter()
+                .map(|Placement { row, column, span }| (row, column, span.columns))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            cells(3),
+            [(0, 0, 1), (0, 1, 1), (1, 1, 2), (2, 0, 1), (3, 0, 3)]
+        );
+        assert_eq!(
+            cells(1),
+            [(0, 0, 1), (2, 0, 1), (3, 0, 1), (4, 0, 1), (5, 0, 1)]
+        );
+    }
+
+    #[test]
+    fn grow_to_fit_test() {
+        let mut tracks = [10.0, 30.0];
+        grow_to_fit(&mut tracks, 60.0, 10.0.into());
+        assert_eq!(tracks, [15.0, 35.0]);
+
+        grow_to_fit(&mut tracks, 20.0, 10.0.into());
+        assert_eq!(tracks, [15.0, 35.0]);
+    }
+
+    #[test]
+    fn allocate_space_test() {
+        let tracks = [
+            Track::auto(),
+            Track::fr(1.0),
+            Track::minmax(50.0, 150.0),
+            Track::fr(2.0),
+        ];
+
+        let mut sizes = [80.0, 0.0, 50.0, 0.0];
+        allocate_space(&mut sizes, &tracks, 500.0);
+        assert_eq!(sizes, [80.0, 90.0, 150.0, 180.0]);
+
+        let mut sizes = [80.0, 0.0, 50.0, 0.0];
+        allocate_space(&mut sizes, &tracks, 200.0);
+        assert_eq!(sizes, [80.0, 0.0, 120.0, 0.0]);
+
+        let mut sizes = [40.0, 100.0];
+        allocate_space(
+            &mut sizes,
+            &[Track::minmax(20.0, Breadth::Fraction(1.0))],
+            300.0,
+        );
+        assert_eq!(sizes, [150.0, 150.0]);
+    }
 }
@@ -1,7 +1,12 @@
 //! A container to layout widget in a grid.
 
+mod alignment;
 mod layout;
+mod template;
+mod track;
 mod types;
 mod widget;
 
+pub use alignment::CellAlignment;
+pub use track::{Breadth, Track};
 pub use types::{Grid, GridRow};
@@ -0,0 +1,135 @@
+//! Named areas of a [`Grid`](super::Grid), like the CSS `grid-template-areas`.
+
+use super::{layout::Placement, types::Span};
+
+/// The named areas of a [`Grid`](super::Grid) and the cells they cover.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct Template {
+    /// The areas with their placement, in the order of their first cell.
+    areas: Vec<(String, Placement)>,
+}
+
+impl Template {
+    /// Parses the rows of a template, each naming the area of every column
+    /// separated by whitespace. A `.` names no area.
+    ///
+    /// Like an invalid CSS `grid-template-areas`, the template has no areas
+    /// if the rows have different numbers of columns. Areas whose cells do
+    /// not form a rectangle are left out.
+    pub(super) fn parse<S: AsRef<str>>(rows: impl IntoIterator<Item = S>) -> Self {
+        let rows: Vec<Vec<String>> = rows
+            .into_iter()
+            .map(|row| row.as_ref().split_whitespace().map(String::from).collect())
+            .collect();
+
+        if let Some(first) = rows.first() {
+            if rows.iter().any(|row| row.len() != first.len()) {
+                return Self::default();
+            }
+        }
+
+        let mut bounds: Vec<Bounds> = Vec::new();
+        for (row, names) in rows.iter().enumerate() {
+            for (column, name) in names.iter().enumerate().filter(|(_, name)| *name != ".") {
+                match bounds.iter_mut().find(|bounds| &bounds.name == name) {
+                    Some(bounds) => {
+                        bounds.start = (bounds.start.0.min(row), bounds.start.1.min(column));
+                        bounds.end = (bounds.end.0.max(row), bounds.end.1.max(column));
+                        bounds.cells += 1;
+                    }
+                    None => bounds.push(Bounds {
+                        name: name.clone(),
+                        start: (row, column),
+                        end: (row, column),
+                        cells: 1,
+                    }),
+                }
+            }
+        }
+
+        let areas = bounds
+            .into_iter()
+            .filter_map(|bounds| {
+                let span = Span {
+                    rows: bounds.end.0 - bounds.start.0 + 1,
+                    columns: bounds.end.1 - bounds.start.1 + 1,
+                };
+                if bounds.cells != span.rows * span.columns {
+                    return None;
+                }
+
+                let placement = Placement {
+                    row: bounds.start.0,
+                    column: bounds.start.1,
+                    span,
+                };
+                Some((bounds.name, placement))
+            })
+            .collect();
+
+        Self { areas }
+    }
+
+    /// Returns the number of columns covered by the areas.
+    pub(super) fn columns(&self) -> usize {
+        self.areas
+            .iter()
+            .map(|(_, placement)| placement.column + placement.span.columns)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the placement of the area of the given name, if any.
+    pub(super) fn placement(&self, name: &str) -> Option<Placement> {
+        self.areas
+            .iter()
+            .find(|(area, _)| area == name)
+            .map(|&(_, placement)| placement)
+    }
+}
+
+/// The first and last row and column of the cells of an area while parsing.
+struct Bounds {
+    name: String,
+    start: (usize, usize),
+    end: (usize, usize),
+    cells: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Template;
+    use crate::widget::grid::{layout::Placement, types::Span};
+
+    #[test]
+    fn parse_test() {
+        let template = Template::parse([
+            "header header header",
+            "sidebar main main",
+            "sidebar main main",
+            ". footer footer",
+        ]);
+
+        let placement = |row, column, rows, columns| {
+            Some(Placement {
+                row,
+                column,
+                span: Span { rows, columns },
+            })
+        };
+        assert_eq!(template.placement("header"), placement(0, 0, 1, 3));
+        assert_eq!(template.placement("sidebar"), placement(1, 0, 2, 1));
+        assert_eq!(template.placement("main"), placement(1, 1, 2, 2));
+        assert_eq!(template.placement("footer"), placement(3, 1, 1, 2));
+        assert_eq!(template.placement("."), None);
+    }
+
+    #[test]
+    fn parse_invalid_test() {
+        let template = Template::parse(["a a", "a b"]);
+        assert_eq!(template.placement("a"), None);
+        assert!(template.placement("b").is_some());
+
+        assert_eq!(Template::parse(["a a", "b"]), Template::default());
+    }
+}
@@ -0,0 +1,128 @@
+use iced::{Length, Pixels};
+
+/// The size of a row or a column of a [`Grid`](super::Grid), between a
+/// minimum and a maximum [`Breadth`] like the CSS `minmax()` function.
+///
+/// A track is never smaller than the space needed to fit its contents. The
+/// space left after every track got its minimum first grows the tracks with
+/// a fixed maximum up to it and is then shared by the fractional tracks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Track {
+    pub(super) min: Breadth,
+    pub(super) max: Breadth,
+}
+
+/// A bound of the size of a [`Track`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Breadth {
+    /// The space needed to fit the contents of the track.
+    Auto,
+    /// A fixed size in pixels.
+    Fixed(f32),
+    /// A share of the space left by the other tracks, like the CSS `fr` unit.
+    ///
+    /// As a minimum it is the same as [`Breadth::Auto`].
+    Fraction(f32),
+}
+
+impl Track {
+    /// A track fitting its contents, like the CSS `auto` size.
+    #[must_use]
+    pub const fn auto() -> Self {
+        Self {
+            min: Breadth::Auto,
+            max: Breadth::Auto,
+        }
+    }
+
+    /// A track of a fixed size in pixels.
+    #[must_use]
+    pub const fn fixed(size: f32) -> Self {
+        Self {
+            min: Breadth::Fixed(size),
+            max: Breadth::Fixed(size),
+        }
+    }
+
+    /// A track taking the given share of the space left by the other tracks,
+    /// like the CSS `fr` unit.
+    #[must_use]
+    pub const fn fr(share: f32) -> Self {
+        Self {
+            min: Breadth::Auto,
+            max: Breadth::Fraction(share),
+        }
+    }
+
+    /// A track between the given minimum and maximum, like the CSS
+    /// `minmax()` function.
+    ///
+    /// For example, `Track::minmax(200.0, Breadth::Fraction(1.0))` takes a
+    /// share of the remaining space but is at least 200 pixels.
+    #[must_use]
+    pub fn minmax(min: impl Into<Breadth>, max: impl Into<Breadth>) -> Self {
+        Self {
+            min: min.into(),
+            max: max.into(),
+        }
+    }
+
+    /// Returns the size of the track fitting contents of the given size.
+    pub(super) fn base_size(self, content: f32) -> f32 {
+        match self.min {
+            Breadth::Fixed(size) => content.max(size),
+            Breadth::Auto | Breadth::Fraction(_) => content,
+        }
+    }
+
+    /// Returns the size up to which the track of the given size grows
+    /// before the fractional tracks get the remaining space.
+    pub(super) fn growth_limit(self, size: f32) -> f32 {
+        match self.max {
+            Breadth::Fixed(max) => size.max(max),
+            Breadth::Auto | Breadth::Fraction(_) => size,
+        }
+    }
+
+    /// Returns the share of the remaining space of a fractional track.
+    pub(super) fn fraction(self) -> f32 {
+        match self.max {
+            Breadth::Fraction(share) => share.max(0.0),
+            Breadth::Auto | Breadth::Fixed(_) => 0.0,
+        }
+    }
+}
+
+impl From<f32> for Breadth {
+    fn from(size: f32) -> Self {
+        Self::Fixed(size)
+    }
+}
+
+impl From<Length> for Track {
+    fn from(length: Length) -> Self {
+        match length {
+            Length::Fill | Length::FillPortion(_) => Self::fr(f32::from(length.fill_factor())),
+            Length::Shrink => Self::auto(),
+            Length::Fixed(size) => Self::fixed(size),
+        }
+    }
+}
+
+impl From<f32> for Track {
+    fn from(size: f32) -> Self {
+        Self::fixed(size)
+    }
+}
+
+impl From<u16> for Track {
+    fn from(size: u16) -> Self {
+        Self::fixed(f32::from(size))
+    }
+}
+
+impl From<Pixels> for Track {
+    fn from(size: Pixels) -> Self {
+        Self::fixed(size.0)
+    }
+}
@@ -1,12 +1,27 @@
+use super::{
+    alignment::CellAlignment,
+    layout::{flow, place, Placement},
+    template::Template,
+    track::Track,
+};
+use crate::core::breakpoints::Breakpoints;
 use iced::{
     advanced::renderer,
     alignment::{Horizontal, Vertical},
     Element, Length, Padding, Pixels,
 };
+use std::collections::HashMap;
 
 /// A container that distributes its contents in a grid of rows and columns.
 ///
-/// The number of columns is determined by the row with the most elements.
+/// The elements of every [`GridRow`] are placed from left to right in the
+/// next free cells, skipping the cells covered by elements of earlier rows
+/// spanning several rows. The number of columns is determined by the row
+/// reaching furthest, unless it is set by [`Grid::breakpoints`].
+///
+/// Elements can also be placed in the named areas of a template with
+/// [`Grid::template_areas`] and [`Grid::area`], the rows then filling the
+/// cells left free.
 #[allow(missing_debug_implementations)]
 pub struct Grid<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     pub(super) rows: Vec<GridRow<'a, Message, Theme, Renderer>>,
@@ -17,8 +32,13 @@ pub struct Grid<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     pub(super) padding: Padding,
     pub(super) width: Length,
     pub(super) height: Length,
-    pub(super) column_widths: Vec<Length>,
-    pub(super) row_heights: Vec<Length>,
+    pub(super) column_tracks: Vec<Track>,
+    pub(super) row_tracks: Vec<Track>,
+    pub(super) breakpoints: Breakpoints<usize>,
+    pub(super) template: Template,
+    pub(super) areas: Vec<(String, Element<'a, Message, Theme, Renderer>)>,
+    pub(super) column_alignments: HashMap<usize, CellAlignment>,
+    pub(super) row_alignments: HashMap<usize, CellAlignment>,
 }
 
 impl<'a, Message, Theme, Renderer> Default for Grid<'a, Message, Theme, Renderer>
@@ -35,8 +55,13 @@ where
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
-            column_widths: vec![Length::Fill],
-            row_heights: vec![Length::Fill],
+            column_tracks: vec![Track::fr(1.0)],
+            row_tracks: vec![Track::fr(1.0)],
+            breakpoints: Breakpoints::default(),
+            template: Template::default(),
+            areas: Vec::new(),
+            column_alignments: HashMap::new(),
+            row_alignments: HashMap::new(),
         }
     }
 }
@@ -83,6 +108,30 @@ where
         self
     }
 
+    /// Overrides the alignment of the widgets in the given column, for example
+    /// `.column_alignment(1, Horizontal::Right)` to right-align numbers.
+    ///
+    /// Alignments set for a row take precedence. Setting only one axis keeps
+    /// the alignment previously set for the other.
+    #[must_use]
+    pub fn column_alignment(mut self, column: usize, align: impl Into<CellAlignment>) -> Self {
+        let alignment = self.column_alignments.entry(column).or_default();
+        *alignment = align.into().or(*alignment);
+        self
+    }
+
+    /// Overrides the alignment of the widgets in the given row.
+    ///
+    /// Alignments set for a single cell with [`GridRow::push_aligned()`] take
+    /// precedence. Setting only one axis keeps the alignment previously set for
+    /// the other.
+    #[must_use]
+    pub fn row_alignment(mut self, row: usize, align: impl Into<CellAlignment>) -> Self {
+        let alignment = self.row_alignments.entry(row).or_default();
+        *alignment = align.into().or(*alignment);
+        self
+    }
+
     /// Sets the spacing between rows and columns. To set row and column spacing separately, use
     /// [`Self::column_spacing()`] and [`Self::row_spacing()`].
     #[must_use]
@@ -128,83 +177,231 @@ where
         self
     }
 
-    /// Sets the column width.
+    /// Sets the column width, as a [`Length`], a size in pixels or a [`Track`].
     ///
     /// The same setting will be used for all columns. To set separate values for each column, use
     /// [`Self::column_widths()`]. Columns are never smaller than the space needed to fit their
     /// contents.
     #[must_use]
-    pub fn column_width(mut self, width: impl Into<Length>) -> Self {
-        self.column_widths = vec![width.into()];
+    pub fn column_width(mut self, width: impl Into<Track>) -> Self {
+        self.column_tracks = vec![width.into()];
         self
     }
 
-    /// Sets the row height.
+    /// Sets the row height, as a [`Length`], a size in pixels or a [`Track`].
     ///
     /// The same setting will be used for all rows. To set separate values for each row, use
     /// [`Self::row_heights()`]. Rows are never smaller than the space needed to fit their
     /// contents.
     #[must_use]
-    pub fn row_height(mut self, height: impl Into<Length>) -> Self {
-        self.row_heights = vec![height.into()];
+    pub fn row_height(mut self, height: impl Into<Track>) -> Self {
+        self.row_tracks = vec![height.into()];
         self
     }
 
-    /// Sets a separate width for each column.
+    /// Sets a separate width for each column, as [`Length`]s, sizes in pixels or [`Track`]s.
     ///
     /// Columns are never smaller than the space needed to fit their contents. When supplying fewer
     /// values than the number of columns, values are are repeated using
     /// [`std::iter::Iterator::cycle()`].
     #[must_use]
-    pub fn column_widths(mut self, widths: &[Length]) -> Self {
-        self.column_widths = widths.into();
+    pub fn column_widths<T>(mut self, widths: &[T]) -> Self
+    where
+        T: Into<Track> + Copy,
+    {
+        self.column_tracks = widths.iter().map(|&width| width.into()).collect();
         self
     }
 
-    /// Sets a separate height for each row.
+    /// Sets a separate height for each row, as [`Length`]s, sizes in pixels or [`Track`]s.
     ///
     /// Rows are never smaller than the space needed to fit their contents. When supplying fewer
     /// values than the number of rows, values are are repeated using
     /// [`std::iter::Iterator::cycle()`].
     #[must_use]
-    pub fn row_heights(mut self, heights: &[Length]) -> Self {
-        self.row_heights = heights.into();
+    pub fn row_heights<T>(mut self, heights: &[T]) -> Self
+    where
+        T: Into<Track> + Copy,
+    {
+        self.row_tracks = heights.iter().map(|&height| height.into()).collect();
         self
     }
 
+    /// Sets the number of columns from the width available to the grid, as
+    /// pairs of a minimum width and the column count applying from it on.
+    ///
+    /// For example, `.breakpoints([(0.0, 1), (600.0, 2), (1200.0, 4)])` uses
+    /// a single column below 600 pixels and four columns from 1200 pixels on.
+    /// The elements of all rows then flow into the next free cells of that
+    /// many columns, keeping their spans. Below the smallest breakpoint the
+    /// [`GridRow`]s are kept.
+    #[must_use]
+    pub fn breakpoints(mut self, breakpoints: impl IntoIterator<Item = (f32, usize)>) -> Self {
+        self.breakpoints = Breakpoints::new(breakpoints);
+        self
+    }
+
+    /// Sets the named areas of the grid, like the CSS `grid-template-areas`.
+    ///
+    /// Every string is a row naming the area of each of its columns,
+    /// separated by whitespace, with `.` for a cell without area. For example,
+    /// `.template_areas(["header header", "sidebar main"])` describes a header
+    /// above a sidebar and a main area. Elements are placed in the areas with
+    /// [`Self::area()`].
+    ///
+    /// A template whose rows have different numbers of columns is ignored,
+    /// and so are the areas whose cells do not form a rectangle. The
+    /// elements of ignored areas are placed in the next free cells.
+    #[must_use]
+    pub fn template_areas<S: AsRef<str>>(mut self, rows: impl IntoIterator<Item = S>) -> Self {
+        self.template = Template::parse(rows);
+        self
+    }
+
+    /// Places a widget in the area of the given name of the template set with
+    /// [`Self::template_areas()`], spanning all its cells.
+    ///
+    /// If the template has no area of the given name, the widget is placed
+    /// in the next free cell, like an element with an unknown CSS `grid-area`.
+    #[must_use]
+    pub fn area<E>(mut self, name: impl Into<String>, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.areas.push((name.into(), element.into()));
+        self
+    }
+
+    /// Returns the placements of the elements of the rows followed by those
+    /// of the areas, for the given available width.
+    pub(super) fn placements(&self, width: f32) -> Vec<Placement> {
+        let areas: Vec<_> = self
+            .areas
+            .iter()
+            .map(|(name, _)| self.template.placement(name))
+            .collect();
+        let reserved: Vec<_> = areas.iter().flatten().copied().collect();
+
+        let mut placements = match self.breakpoints.get(width) {
+            Some(&columns) => flow(
+                self.rows.iter().flat_map(|row| row.spans.iter().copied()),
+                columns,
+                &reserved,
+            ),
+            None => place(self.rows.iter().map(|row| row.spans.as_slice()), &reserved),
+        };
+
+        // Elements of unknown areas are placed in the next free cells.
+        let mut taken = placements.clone();
+        taken.extend_from_slice(&reserved);
+        let columns = self.breakpoints.get(width).copied().unwrap_or_else(|| {
+            taken
+                .iter()
+                .map(|placement| placement.column + placement.span.columns)
+                .fold(self.template.columns(), usize::max)
+        });
+        let mut unplaced = flow(
+            std::iter::repeat(Span::ONE).take(areas.len() - reserved.len()),
+            columns,
+            &taken,
+        )
+        .into_iter();
+
+        placements.extend(
+            areas
+                .into_iter()
+                .filter_map(|placement| placement.or_else(|| unplaced.next())),
+        );
+        placements
+    }
+
+    /// Returns the alignment of every element at its placement, in the order
+    /// of [`Self::placements()`].
+    pub(super) fn alignments(&self, placements: &[Placement]) -> Vec<(Horizontal, Vertical)> {
+        let grid = CellAlignment::new(self.horizontal_alignment, self.vertical_alignment);
+        let area_count = self.areas.len();
+
+        self.rows
+            .iter()
+            .flat_map(|row| row.alignments.iter().copied())
+            .chain(std::iter::repeat(CellAlignment::default()).take(area_count))
+            .zip(placements)
+            .map(|(cell, placement)| {
+                let alignment = resolve_alignment(
+                    cell,
+                    self.row_alignments.get(&placement.row).copied(),
+                    self.column_alignments.get(&placement.column).copied(),
+                    grid,
+                );
+                (
+                    alignment.horizontal.unwrap_or(self.horizontal_alignment),
+                    alignment.vertical.unwrap_or(self.vertical_alignment),
+                )
+            })
+            .collect()
+    }
+
     pub(super) fn elements_iter(
         &self,
     ) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
-        self.rows.iter().flat_map(|row| row.elements.iter())
+        self.rows
+            .iter()
+            .flat_map(|row| row.elements.iter())
+            .chain(self.areas.iter().map(|(_, element)| element))
     }
 
     pub(super) fn elements_iter_mut(
         &mut self,
     ) -> impl Iterator<Item = &mut Element<'a, Message, Theme, Renderer>> {
-        self.rows.iter_mut().flat_map(|row| row.elements.iter_mut())
+        self.rows
+            .iter_mut()
+            .flat_map(|row| row.elements.iter_mut())
+            .chain(self.areas.iter_mut().map(|(_, element)| element))
     }
 
-    pub(super) fn column_count(&self) -> usize {
+    pub(super) fn element_count(&self) -> usize {
         self.rows
             .iter()
             .map(|row| row.elements.len())
-            .max()
-            .unwrap_or(0)
-    }
-
-    pub(super) fn row_count(&self) -> usize {
-        self.rows.len()
+            .sum::<usize>()
+            + self.areas.len()
     }
+}
 
-    pub(super) fn element_count(&self) -> usize {
-        self.rows.iter().map(|row| row.elements.len()).sum()
-    }
+/// Returns the alignment of a cell falling back to those of its row, its column
+/// and the grid, in this order.
+fn resolve_alignment(
+    cell: CellAlignment,
+    row: Option<CellAlignment>,
+    column: Option<CellAlignment>,
+    grid: CellAlignment,
+) -> CellAlignment {
+    cell.or(row.unwrap_or_default())
+        .or(column.unwrap_or_default())
+        .or(grid)
 }
 
 /// A container that distributes its contents in a row of a [`crate::Grid`].
 #[allow(missing_debug_implementations)]
 pub struct GridRow<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     pub(crate) elements: Vec<Element<'a, Message, Theme, Renderer>>,
+    pub(super) spans: Vec<Span>,
+    pub(super) alignments: Vec<CellAlignment>,
+}
+
+/// The number of rows and columns an element of a [`GridRow`] spans.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Span {
+    pub(super) rows: usize,
+    pub(super) columns: usize,
+}
+
+impl Span {
+    /// The span of an element covering a single cell.
+    pub(super) const ONE: Self = Self {
+        rows: 1,
+        columns: 1,
+    };
 }
 
 impl<'a, Message, Theme, Renderer> Default for GridRow<'a, Message, Theme, Renderer>
@@ -214,6 +411,8 @@ where
     fn default() -> Self {
         Self {
             elements: Vec::new(),
+            spans: Vec::new(),
+            alignments: Vec::new(),
         }
     }
 }
@@ -232,17 +431,116 @@ where
     #[must_use]
     pub fn with_elements(children: Vec<impl Into<Element<'a, Message, Theme, Renderer>>>) -> Self {
         Self {
+            spans: vec![Span::ONE; children.len()],
+            alignments: vec![CellAlignment::default(); children.len()],
             elements: children.into_iter().map(std::convert::Into::into).collect(),
         }
     }
 
     /// Adds a widget to the [`GridRow`].
     #[must_use]
-    pub fn push<E>(mut self, element: E) -> Self
+    pub fn push<E>(self, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.push_spanning(element, 1, 1)
+    }
+
+    /// Adds a widget to the [`GridRow`] spanning `row_span` rows and
+    /// `column_span` columns.
+    ///
+    /// The widget covers the cells below it in the following rows, whose
+    /// elements are placed in the next free cells. Spans of zero count as one.
+    #[must_use]
+    pub fn push_spanning<E>(mut self, element: E, row_span: usize, column_span: usize) -> Self
     where
         E: Into<Element<'a, Message, Theme, Renderer>>,
     {
         self.elements.push(element.into());
+        self.spans.push(Span {
+            rows: row_span.max(1),
+            columns: column_span.max(1),
+        });
+        self.alignments.push(CellAlignment::default());
         self
     }
+
+    /// Adds a widget to the [`GridRow`] with its own alignment within its
+    /// cell, taking precedence over the alignments of its row and column.
+    #[must_use]
+    pub fn push_aligned<E>(mut self, element: E, align: impl Into<CellAlignment>) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.elements.push(element.into());
+        self.spans.push(Span::ONE);
+        self.alignments.push(align.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_alignment, Grid, Placement, Span};
+    use crate::widget::grid::{CellAlignment, Track};
+    use iced::{
+        alignment::{Horizontal, Vertical},
+        widget::Space,
+        Length, Pixels,
+    };
+
+    #[test]
+    fn track_conversion_test() {
+        let grid: Grid<'_, ()> = Grid::new().column_width(100.0).row_height(50);
+        assert_eq!(grid.column_tracks, vec![Track::fixed(100.0)]);
+        assert_eq!(grid.row_tracks, vec![Track::fixed(50.0)]);
+
+        let grid: Grid<'_, ()> = Grid::new()
+            .column_widths(&[Pixels(20.0), Pixels(30.0)])
+            .row_heights(&[Length::Fill, Length::Fixed(10.0)]);
+        assert_eq!(
+            grid.column_tracks,
+            vec![Track::fixed(20.0), Track::fixed(30.0)]
+        );
+        assert_eq!(grid.row_tracks, vec![Track::fr(1.0), Track::fixed(10.0)]);
+    }
+
+    #[test]
+    fn unknown_area_test() {
+        let grid: Grid<'_, ()> = Grid::new()
+            .template_areas(["a b", "c c"])
+            .area("c", Space::new(0, 0))
+            .area("d", Space::new(0, 0))
+            .area("a", Space::new(0, 0));
+
+        let placement = |row, column, columns| Placement {
+            row,
+            column,
+            span: Span { rows: 1, columns },
+        };
+        assert_eq!(
+            grid.placements(100.0),
+            vec![placement(1, 0, 2), placement(0, 1, 1), placement(0, 0, 1)]
+        );
+    }
+
+    #[test]
+    fn resolve_alignment_test() {
+        let grid = CellAlignment::new(Horizontal::Left, Vertical::Center);
+        let column = Some(CellAlignment::from(Horizontal::Right));
+        let row = Some(CellAlignment::from(Vertical::Top));
+
+        assert_eq!(
+            resolve_alignment(CellAlignment::default(), row, column, grid),
+            CellAlignment::new(Horizontal::Right, Vertical::Top)
+        );
+        assert_eq!(
+            resolve_alignment(Horizontal::Center.into(), row, column, grid),
+            CellAlignment::new(Horizontal::Center, Vertical::Top)
+        );
+        assert_eq!(
+            resolve_alignment(CellAlignment::default(), None, None, grid),
+            grid
+        );
+    }
 }
@@ -30,31 +30,31 @@ where
         }
 
         assert!(
-            !self.column_widths.is_empty(),
+            !self.column_tracks.is_empty(),
             "At least one column width is required"
         );
         assert!(
-            !self.row_heights.is_empty(),
+            !self.row_tracks.is_empty(),
             "At least one row height is required"
         );
 
+        let available_width = limits.shrink(self.padding).width(self.width).max().width;
+        let placements = self.placements(available_width);
+
         layout(
             tree,
             renderer,
             limits,
-            self.column_count(),
-            self.row_count(),
-            self.element_count(),
-            &self.rows,
+            &self.elements_iter().collect::<Vec<_>>(),
+            &placements,
             self.column_spacing,
             self.row_spacing,
             self.padding,
-            self.horizontal_alignment,
-            self.vertical_alignment,
+            &self.alignments(&placements),
             self.width,
             self.height,
-            &self.column_widths,
-            &self.row_heights,
+            &self.column_tracks,
+            &self.row_tracks,
         )
     }
 
@@ -1,394 +1,483 @@
-#![allow(clippy::type_complexity)]
-use crate::style::header::StyleSheet;
-use iced::advanced::renderer;
-use iced_core::{
-	event, layout, mouse,
-	widget::{self, Tree},
-	Alignment, Clipboard, Element, Event, Layout, Length, Padding, Rectangle, Shell, Widget,
+//! A header row of named columns whose widths can be changed by dragging the
+//! dividers between them, which can be placed above any list.
+//!
+//! *This API requires the following crate features to be activated: header*
+
+use iced::{
+    advanced::{
+        layout::{flex, Limits, Node},
+        renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, mouse,
+    widget::Space,
+    Alignment, Border, Element, Event, Length, Padding, Rectangle, Shadow, Size,
 };
 
-use iced::{widget::space::Space, Size};
+use crate::core::resize::ResizeState;
 
-mod state;
-pub use state::State;
+pub use crate::core::resize::ResizeEvent;
+pub use crate::style::{
+    header::{Catalog, Style},
+    status::{Status, StyleFn},
+};
 
-pub struct Header<'a, Message, Theme, Renderer = iced::Renderer>
+/// The minimum width of a column, in pixels.
+const MIN_COLUMN_WIDTH: f32 = 30.0;
+
+/// The width of the line drawn over the hovered or dragged divider.
+const DIVIDER_WIDTH: f32 = 2.0;
+
+/// The message callback of a [`Header`] and the leeway around the dividers.
+type OnResize<'a, Message> = (u16, Box<dyn Fn(ResizeEvent) -> Message + 'a>);
+
+/// A header row of named columns whose widths can be changed by dragging the
+/// dividers between them.
+///
+/// The [`Header`] reports the new widths of the two columns next to a dragged
+/// divider, so the application can apply them to the header and to the list
+/// below it.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::text;
+/// # use iced_aw::{header::ResizeEvent, Header};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Resize(ResizeEvent),
+/// }
+///
+/// let header = Header::new(
+///     vec![
+///         (String::from("name"), text("Name").width(200).into()),
+///         (String::from("size"), text("Size").width(100).into()),
+///     ],
+///     None,
+///     None,
+/// )
+/// .on_resize(5, Message::Resize);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Header<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
 where
-	Renderer: renderer::Renderer,
-	Theme: StyleSheet,
-	Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: Catalog,
 {
-	spacing: u16,
-	width: Length,
-	height: Length,
-	state: State,
-	leeway: u16,
-	on_resize: Option<(u16, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
-	children: Vec<Element<'a, Message, Theme, Renderer>>,
-	left_margin: bool,
-	right_margin: bool,
-	names: Vec<String>,
-	style: <Theme as StyleSheet>::Style,
+    /// The spacing between the columns of the [`Header`].
+    spacing: u16,
+    /// The width of the [`Header`].
+    width: Length,
+    /// The height of the [`Header`].
+    height: Length,
+    /// The leeway around the dividers and the message produced when resizing.
+    on_resize: Option<OnResize<'a, Message>>,
+    /// The columns of the [`Header`], surrounded by the optional margins.
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// Whether the first child is a margin.
+    left_margin: bool,
+    /// Whether the last child is a margin.
+    right_margin: bool,
+    /// The names of the columns.
+    names: Vec<String>,
+    /// The style of the [`Header`].
+    class: Theme::Class<'a>,
 }
 
 impl<'a, Message, Theme, Renderer> Header<'a, Message, Theme, Renderer>
 where
-	Renderer: renderer::Renderer,
-	Theme: StyleSheet,
-	Message: Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + Catalog,
+    Message: 'a,
 {
-	pub fn new(
-		state: State,
-		headers: Vec<(String, Element<'a, Message, Theme, Renderer>)>,
-		left_margin: Option<Length>,
-		right_margin: Option<Length>,
-	) -> Self
-	where
-		Theme: iced_style::container::StyleSheet,
-		Renderer: 'a + iced_core::Renderer,
-		Message: 'a,
-	{
-		let mut names = vec![];
-		let mut left = false;
-		let mut right = false;
-
-		let mut children = vec![];
-
-		if let Some(margin) = left_margin {
-			children.push(Space::with_width(margin).into());
-			left = true;
-		}
-
-		for (key, container) in headers {
-			names.push(key);
-
-			// add container to children
-			children.push(container.into());
-		}
-
-		if let Some(margin) = right_margin {
-			children.push(Space::with_width(margin).into());
-			right = true;
-		}
-
-		Self {
-			spacing: 0,
-			width: Length::Fill,
-			height: Length::Fill,
-			leeway: 0,
-			state,
-			on_resize: None,
-			children,
-			left_margin: left,
-			right_margin: right,
-			names,
-			style: Default::default(),
-		}
-	}
-
-	pub fn spacing(mut self, units: u16) -> Self {
-		self.spacing = units;
-		self
-	}
-
-	pub fn width(mut self, width: Length) -> Self {
-		self.width = width;
-		self
-	}
-
-	pub fn height(mut self, height: Length) -> Self {
-		self.height = height;
-		self
-	}
-
-	pub fn on_resize<F>(mut self, leeway: u16, f: F) -> Self
-	where
-		F: 'a + Fn(ResizeEvent) -> Message,
-	{
-		self.leeway = leeway;
-		self.on_resize = Some((leeway, Box::new(f)));
-		self
-	}
-
-	fn trigger_resize(
-		&self,
-		left_name: String,
-		left_width: u16,
-		right_name: String,
-		right_width: u16,
-		shell: &mut Shell<'_, Message>,
-	) {
-		if let Some((_, on_resize)) = &self.on_resize {
-			//TODO: Update
-			shell.publish(on_resize(ResizeEvent::ResizeColumn {
-				left_name,
-				left_width,
-				right_name,
-				right_width,
-			}));
-		}
-	}
-
-	fn trigger_finished(&self, shell: &mut Shell<'_, Message>) {
-		if let Some((_, on_resize)) = &self.on_resize {
-			shell.publish(on_resize(ResizeEvent::Finished));
-		}
-	}
+    /// Creates a new [`Header`] with the given named columns.
+    ///
+    /// It expects:
+    ///     * the names and the content of the columns.
+    ///     * the width of an optional margin before the first column.
+    ///     * the width of an optional margin after the last column.
+    #[must_use]
+    pub fn new(
+        headers: Vec<(String, Element<'a, Message, Theme, Renderer>)>,
+        left_margin: Option<Length>,
+        right_margin: Option<Length>,
+    ) -> Self {
+        let mut names = Vec::with_capacity(headers.len());
+        let mut children = Vec::with_capacity(headers.len() + 2);
+
+        if let Some(margin) = left_margin {
+            children.push(Space::with_width(margin).into());
+        }
+
+        for (name, content) in headers {
+            names.push(name);
+            children.push(content);
+        }
+
+        if let Some(margin) = right_margin {
+            children.push(Space::with_width(margin).into());
+        }
+
+        Self {
+            spacing: 0,
+            width: Length::Fill,
+            height: Length::Shrink,
+            on_resize: None,
+            children,
+            left_margin: left_margin.is_some(),
+            right_margin: right_margin.is_some(),
+            names,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the spacing between the columns of the [`Header`].
+    #[must_use]
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the width of the [`Header`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Header`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the message that will be produced while a divider between two
+    /// columns is dragged and when it is released.
+    ///
+    /// A divider can be grabbed within `leeway` pixels on either side.
+    #[must_use]
+    pub fn on_resize<F>(mut self, leeway: u16, f: F) -> Self
+    where
+        F: 'a + Fn(ResizeEvent) -> Message,
+    {
+        self.on_resize = Some((leeway, Box::new(f)));
+        self
+    }
+
+    /// Sets the style of the [`Header`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme, Style>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme, Style>).into();
+        self
+    }
+
+    /// Sets the class of the [`Header`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
 }
 
-impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-	for Header<'a, Message, Theme, Renderer>
+impl<Message, Theme, Renderer> Header<'_, Message, Theme, Renderer>
 where
-	Renderer: renderer::Renderer,
-	Theme: StyleSheet,
-	Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: Catalog,
 {
-	fn children(&self) -> Vec<Tree> {
-		self.children.iter().map(Tree::new).collect()
-	}
-
-	fn diff(&self, tree: &mut Tree) {
-		tree.diff_children(&self.children);
-	}
-
-	fn size(&self) -> Size<Length> {
-		Size {
-			width: self.width,
-			height: self.height,
-		}
-	}
-
-	fn layout(
-		&self,
-		tree: &mut Tree,
-		renderer: &Renderer,
-		limits: &layout::Limits,
-	) -> layout::Node {
-		let limits = limits.width(self.width).height(self.height);
-		let mut trees = self.children();
-
-		layout::flex::resolve(
-			layout::flex::Axis::Horizontal,
-			renderer,
-			&limits,
-			self.width,
-			self.height,
-			Padding::ZERO,
-			self.spacing as f32,
-			Alignment::Start,
-			&self.children,
-			&mut trees,
-		)
-	}
-
-	fn on_event(
-		&mut self,
-		tree: &mut Tree,
-		event: Event,
-		layout: Layout<'_>,
-		cursor: mouse::Cursor,
-		renderer: &Renderer,
-		clipboard: &mut dyn Clipboard,
-		shell: &mut Shell<'_, Message>,
-		viewport: &Rectangle,
-	) -> event::Status {
-		let cursor_position = cursor.position().unwrap_or_default();
-		let in_bounds = layout.bounds().contains(cursor_position);
-
-		if self.state.resizing || in_bounds {
-			let child_len = self.children.len();
-			let start_offset = if self.left_margin { 1 } else { 0 };
-			let end_offset = if self.right_margin { 1 } else { 0 };
-
-			let dividers = self
-				.children
-				.iter()
-				.enumerate()
-				.zip(layout.children())
-				.filter_map(|((idx, _), layout)| {
-					if idx >= start_offset && idx < (child_len - 1 - end_offset) {
-						Some((idx, layout.position().x + layout.bounds().width))
-					} else {
-						None
-					}
-				})
-				.collect::<Vec<_>>();
-
-			if self.on_resize.is_some() {
-				if !self.state.resizing {
-					self.state.resize_hovering = false;
-				}
-
-				for (idx, divider) in dividers.iter() {
-					if cursor_position.x > (divider - self.leeway as f32)
-						&& cursor_position.x < (divider + self.leeway as f32)
-					{
-						if !self.state.resize_hovering {
-							self.state.resizing_idx = *idx;
-						}
-
-						self.state.resize_hovering = true;
-					}
-				}
-			}
-
-			match event {
-				Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-					if self.state.resize_hovering {
-						self.state.resizing = true;
-						self.state.starting_cursor_pos = Some(cursor_position);
-						self.state.starting_left_width = layout
-							.children()
-							.nth(self.state.resizing_idx)
-							.unwrap()
-							.bounds()
-							.width;
-						self.state.starting_right_width = layout
-							.children()
-							.nth(self.state.resizing_idx + 1)
-							.unwrap()
-							.bounds()
-							.width;
-						return event::Status::Captured;
-					}
-				}
-				Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-					if self.state.resizing {
-						self.state.resizing = false;
-						self.state.starting_cursor_pos.take();
-						// TODO: UPDATE
-						//shell.publish(messages);
-						return event::Status::Captured;
-					}
-				}
-				Event::Mouse(mouse::Event::CursorMoved { position }) => {
-					if self.state.resizing {
-						let delta = position.x - self.state.starting_cursor_pos.unwrap().x;
-
-						let left_width = self.state.starting_left_width;
-						let right_width = self.state.starting_right_width;
-
-						let max_width = left_width + right_width - 30.0;
-
-						let left_width = (left_width + delta).max(30.0).min(max_width) as u16;
-						let left_name = &self.names[self.state.resizing_idx - start_offset];
-						let right_width = (right_width - delta).max(30.0).min(max_width) as u16;
-						let right_name = &self.names[self.state.resizing_idx + 1 - start_offset];
-
-						self.trigger_resize(
-							left_name.clone(),
-							left_width,
-							right_name.clone(),
-							right_width,
-							shell,
-						);
-						return event::Status::Captured;
-					}
-				}
-				_ => {}
-			}
-		} else {
-			self.state.resize_hovering = false;
-		}
-
-		self.children
-			.iter_mut()
-			.zip(&mut tree.children)
-			.zip(layout.children())
-			.map(|((child, state), layout)| {
-				child.as_widget_mut().on_event(
-					state,
-					event.clone(),
-					layout,
-					cursor,
-					renderer,
-					clipboard,
-					shell,
-					viewport,
-				)
-			})
-			.fold(event::Status::Ignored, event::Status::merge)
-	}
-
-	fn draw(
-		&self,
-		tree: &Tree,
-		renderer: &mut Renderer,
-		theme: &Theme,
-		style: &iced_core::renderer::Style,
-		layout: Layout<'_>,
-		cursor: mouse::Cursor,
-		viewport: &Rectangle,
-	) {
-		for ((child, state), layout) in self
-			.children
-			.iter()
-			.zip(&tree.children)
-			.zip(layout.children())
-		{
-			child
-				.as_widget()
-				.draw(state, renderer, theme, style, layout, cursor, viewport);
-		}
-	}
-
-	fn mouse_interaction(
-		&self,
-		tree: &Tree,
-		layout: Layout<'_>,
-		cursor: mouse::Cursor,
-		viewport: &Rectangle,
-		renderer: &Renderer,
-	) -> mouse::Interaction {
-		let bounds = layout.bounds();
-		let cursor_position = cursor.position().unwrap_or_default();
-		let is_mouse_over = bounds.contains(cursor_position);
-
-		if is_mouse_over {
-			mouse::Interaction::Pointer
-		} else {
-			mouse::Interaction::default()
-		}
-	}
-
-	/*fn hash_layout(&self, state: &mut Hasher) {
-		use std::hash::Hash;
-
-		struct Marker;
-		std::any::TypeId::of::<Marker>().hash(state);
-
-		self.width.hash(state);
-		self.height.hash(state);
-		self.spacing.hash(state);
-		self.left_margin.hash(state);
-		self.right_margin.hash(state);
-		self.leeway.hash(state);
-
-		for child in &self.children {
-			child.hash_layout(state);
-		}
-	}*/
+    /// Returns the offset of the first column in the children of the [`Header`].
+    fn start_offset(&self) -> usize {
+        usize::from(self.left_margin)
+    }
+
+    /// Returns the index of the child left of every divider and the x
+    /// position of the divider.
+    fn dividers(&self, layout: Layout<'_>) -> Vec<(usize, f32)> {
+        let start = self.start_offset();
+        let end = self.children.len() - usize::from(self.right_margin);
+
+        layout
+            .children()
+            .enumerate()
+            .skip(start)
+            .take(end.saturating_sub(start + 1))
+            .map(|(idx, layout)| (idx, layout.bounds().x + layout.bounds().width))
+            .collect()
+    }
 }
 
-impl<'a, Message, Theme> From<Header<'a, Message, Theme>>
-	for Element<'a, Message, Theme, iced::Renderer>
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Header<'_, Message, Theme, Renderer>
 where
-	//Renderer: 'a + renderer::Renderer,
-	Theme: 'a + StyleSheet + iced::widget::container::StyleSheet + widget::text::StyleSheet,
-	Message: 'a + Clone,
+    Renderer: renderer::Renderer,
+    Theme: Catalog,
 {
-	fn from(header: Header<'a, Message, Theme>) -> Self {
-		Element::new(header)
-	}
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<ResizeState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(ResizeState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        flex::resolve(
+            flex::Axis::Horizontal,
+            renderer,
+            &limits,
+            self.width,
+            self.height,
+            Padding::ZERO,
+            f32::from(self.spacing),
+            Alignment::Start,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Some((leeway, on_resize)) = &self.on_resize {
+            let state = tree.state.downcast_mut::<ResizeState>();
+            let cursor_position = cursor.position().unwrap_or_default();
+
+            if state.resizing || cursor.is_over(layout.bounds()) {
+                state.hover_dividers(
+                    &self.dividers(layout),
+                    cursor_position.x,
+                    f32::from(*leeway),
+                );
+            } else {
+                state.resize_hovering = false;
+            }
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                    if state.resize_hovering =>
+                {
+                    let width = |idx| {
+                        layout
+                            .children()
+                            .nth(idx)
+                            .map_or(0.0, |layout| layout.bounds().width)
+                    };
+                    let idx = state.resizing_idx;
+                    state.start_resize(cursor_position, width(idx), width(idx + 1));
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                    if state.resizing =>
+                {
+                    let _ = state.finish_resize();
+                    shell.publish(on_resize(ResizeEvent::Finished));
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { position }) if state.resizing => {
+                    let (left_width, right_width) =
+                        state.resized_widths(position.x, MIN_COLUMN_WIDTH);
+                    let left = state.resizing_idx - self.start_offset();
+
+                    if let (Some(left_name), Some(right_name)) =
+                        (self.names.get(left), self.names.get(left + 1))
+                    {
+                        shell.publish(on_resize(ResizeEvent::ResizeColumn {
+                            left_name: left_name.clone(),
+                            left_width: left_width as u16,
+                            right_name: right_name.clone(),
+                            right_width: right_width as u16,
+                        }));
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<ResizeState>();
+        let bounds = layout.bounds();
+        let status = if state.resizing {
+            Status::Pressed
+        } else if state.resize_hovering {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = theme.style(&self.class, status);
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style.border_radius.into(),
+                        width: style.border_width,
+                        color: style.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                background,
+            );
+        }
+
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color,
+                },
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        if state.resizing || state.resize_hovering {
+            if let Some(divider) = layout
+                .children()
+                .nth(state.resizing_idx)
+                .map(|layout| layout.bounds().x + layout.bounds().width)
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: divider - DIVIDER_WIDTH / 2.0,
+                            y: bounds.y,
+                            width: DIVIDER_WIDTH,
+                            height: bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.divider_color,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<ResizeState>();
+
+        if state.resizing || state.resize_hovering {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
 }
 
-#[derive(Debug, Clone)]
-pub enum ResizeEvent {
-	ResizeColumn {
-		left_name: String,
-		left_width: u16,
-		right_name: String,
-		right_width: u16,
-	},
-	Finished,
+impl<'a, Message, Theme, Renderer> From<Header<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + Catalog,
+{
+    fn from(header: Header<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(header)
+    }
 }
@@ -222,6 +222,25 @@ where
     crate::DatePicker::new(show_picker, date, underlay, on_cancel, on_submit)
 }
 
+#[cfg(feature = "table")]
+/// Shortcut helper to create a [`Table`] Widget.
+///
+/// [`Table`]: crate::Table
+pub fn table<'a, T, Message, Theme, Renderer>(
+    columns: Vec<crate::TableColumn<'a, T, Message, Theme, Renderer>>,
+    data: &'a [T],
+) -> crate::Table<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a
+        + crate::style::table_header::Catalog
+        + crate::style::table_row::Catalog
+        + iced::widget::text::Catalog,
+{
+    crate::Table::new(columns, data)
+}
+
 #[cfg(feature = "time_picker")]
 /// Shortcut helper to create a [`DatePicker`] Widget.
 ///
@@ -187,11 +187,10 @@ where
         tree.diff_children(&[&self.container as &dyn Widget<_, _, _>]);
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        state.values = self
-            .options
-            .iter()
-            .map(|_| paragraph::Plain::<Renderer::Paragraph>::default())
-            .collect();
+        // Keep the cached paragraphs, so that only changed options are shaped again.
+        state
+            .values
+            .resize_with(self.options.len(), paragraph::Plain::default);
     }
 
     fn size(&self) -> Size<Length> {
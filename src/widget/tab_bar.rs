@@ -5,32 +5,41 @@
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
 
+mod label;
+mod state;
 pub mod tab_label;
 
 use iced::{
     advanced::{
         layout::{Limits, Node},
-        renderer,
-        widget::Tree,
+        overlay, renderer,
+        widget::{tree, Tree},
         Clipboard, Layout, Shell, Widget,
     },
     alignment::{self, Horizontal, Vertical},
     event,
-    mouse::{self, Cursor},
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor, ScrollDelta},
     touch,
     widget::{
+        button,
         text::{self, LineHeight, Wrapping},
-        Column, Row, Text,
+        Button, Column, Container, Row, Space, Text,
     },
-    Alignment, Background, Border, Color, Element, Event, Font, Length, Padding, Pixels, Point,
-    Rectangle, Shadow, Size,
+    window, Alignment, Background, Border, Color, Element, Event, Font, Length, Padding, Pixels,
+    Point, Rectangle, Shadow, Size, Vector,
 };
 use iced_fonts::{
     required::{icon_to_string, RequiredIcons},
     REQUIRED_FONT,
 };
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, rc::Rc};
+
+use label::{Borrowed, Label};
+use state::State;
+
+use crate::widget::drop_down::{self, DropDown, Offset};
 
 pub use crate::style::{
     tab_bar::{self, Catalog, Style},
@@ -48,6 +57,38 @@ const DEFAULT_CLOSE_SIZE: f32 = 16.0;
 const DEFAULT_PADDING: Padding = Padding::new(5.0);
 /// The default spacing around the tabs.
 const DEFAULT_SPACING: Pixels = Pixels::ZERO;
+/// The width, in pixels, of the indicator showing where a dragged tab is dropped.
+const DROP_INDICATOR_WIDTH: f32 = 2.0;
+/// The distance, in pixels, an overflowing tab strip is scrolled per line of
+/// the mouse wheel.
+const SCROLL_LINE: f32 = 60.0;
+/// The icons of the buttons scrolling the overflowing tab strip and opening
+/// the menu of the hidden tabs.
+const CONTROL_ICONS: [RequiredIcons; 3] = [
+    RequiredIcons::CaretLeftFill,
+    RequiredIcons::CaretRightFill,
+    RequiredIcons::CaretDownFill,
+];
+
+/// The function building the menu listing the hidden tabs of a [`TabBar`].
+type MenuView<'a, Message, Theme, Renderer> =
+    Box<dyn Fn(MenuContent<'a, Message, Theme>) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// The content of the menu listing the hidden tabs of a [`TabBar`].
+struct MenuContent<'a, Message, Theme: Catalog> {
+    /// The labels of the hidden tabs with the messages selecting them.
+    items: Vec<(TabLabel, Message)>,
+    /// The class of the [`TabBar`] the menu is styled with.
+    class: Rc<<Theme as Catalog>::Class<'a>>,
+    /// Whether the menu is open.
+    open: bool,
+    /// The font and size of the icons.
+    icon: (Font, f32),
+    /// The font and size of the texts.
+    text: (Font, f32),
+    /// The size of the button opening the menu.
+    size: f32,
+}
 
 /// A tab bar to show tabs.
 ///
@@ -85,13 +126,15 @@ where
     /// The index of the currently active tab.
     active_tab: usize,
     /// The vector containing the labels of the tabs.
-    tab_labels: Vec<TabLabel>,
+    tab_labels: Vec<Label<'a, Message, Theme, Renderer>>,
     /// The vector containing the indices of the tabs.
     tab_indices: Vec<TabId>,
     /// The function that produces the message when a tab is selected.
     on_select: Box<dyn Fn(TabId) -> Message>,
     /// The function that produces the message when the close icon was pressed.
     on_close: Option<Box<dyn Fn(TabId) -> Message>>,
+    /// The function that produces the message when a tab was dragged to a new index.
+    on_reorder: Option<Box<dyn Fn(TabId, usize) -> Message>>,
     /// The width of the [`TabBar`].
     width: Length,
     /// The width of the tabs of the [`TabBar`].
@@ -114,10 +157,16 @@ where
     font: Option<Font>,
     /// The optional text font of the [`TabBar`].
     text_font: Option<Font>,
-    /// The style of the [`TabBar`].
-    class: <Theme as Catalog>::Class<'a>,
+    /// The style of the [`TabBar`], shared with the menu of the hidden tabs.
+    class: Rc<<Theme as Catalog>::Class<'a>>,
     /// Where the icon is placed relative to text
     position: Position,
+    /// How the tabs are handled when they do not fit the [`TabBar`].
+    overflow: Overflow,
+    /// The function building the menu listing the tabs hidden by scrolling.
+    menu_view: Option<MenuView<'a, Message, Theme, Renderer>>,
+    /// The menu listing the hidden tabs, kept for its overlay.
+    menu: Option<Element<'a, Message, Theme, Renderer>>,
     #[allow(clippy::missing_docs_in_private_items)]
     _renderer: PhantomData<Renderer>,
 }
@@ -136,6 +185,17 @@ pub enum Position {
     Left,
 }
 
+/// How the tabs of a [`TabBar`] are handled when they do not fit its width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// The tabs are shrunk to fit the width, the default.
+    #[default]
+    Shrink,
+    /// The tabs keep the width of their labels and the tab strip is scrolled
+    /// with the mouse wheel and the arrow buttons at its end.
+    Scroll,
+}
+
 impl<'a, Message, TabId, Theme, Renderer> TabBar<'a, Message, TabId, Theme, Renderer>
 where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
@@ -170,9 +230,13 @@ where
         Self {
             active_tab: 0,
             tab_indices: tab_labels.iter().map(|(id, _)| id.clone()).collect(),
-            tab_labels: tab_labels.into_iter().map(|(_, label)| label).collect(),
+            tab_labels: tab_labels
+                .into_iter()
+                .map(|(_, label)| Label::Tab(label))
+                .collect(),
             on_select: Box::new(on_select),
             on_close: None,
+            on_reorder: None,
             width: Length::Fill,
             tab_width: Length::Fill,
             height: Length::Shrink,
@@ -184,8 +248,11 @@ where
             spacing: DEFAULT_SPACING,
             font: None,
             text_font: None,
-            class: <Theme as Catalog>::default(),
+            class: Rc::new(<Theme as Catalog>::default()),
             position: Position::default(),
+            overflow: Overflow::default(),
+            menu_view: None,
+            menu: None,
             _renderer: PhantomData,
         }
     }
@@ -264,6 +331,32 @@ where
         self
     }
 
+    /// Sets the message that will be produced when a tab of the [`TabBar`]
+    /// is dragged to a new position, with its id and its new index.
+    ///
+    /// Setting this enables dragging the tabs, showing where the dragged tab
+    /// will be inserted. The order of the pushed tabs has to be updated
+    /// accordingly.
+    #[must_use]
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(TabId, usize) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets how the tabs are handled when they do not fit the width of the
+    /// [`TabBar`].
+    ///
+    /// With [`Overflow::Scroll`], tabs of a filling width shrink to their
+    /// labels while they overflow.
+    #[must_use]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
     /// Sets the padding of the tabs of the [`TabBar`].
     #[must_use]
     pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
@@ -274,7 +367,26 @@ where
     /// Pushes a [`TabLabel`] to the [`TabBar`].
     #[must_use]
     pub fn push(mut self, id: TabId, tab_label: TabLabel) -> Self {
-        self.tab_labels.push(tab_label);
+        self.tab_labels.push(Label::Tab(tab_label));
+        self.tab_indices.push(id);
+        self
+    }
+
+    /// Pushes a tab showing any element as its label to the [`TabBar`], like
+    /// a text with a [`Badge`](crate::widget::Badge) counting its changes.
+    ///
+    /// The element is drawn with the text color of the tab. The
+    /// [`overflow_menu`](Self::overflow_menu) lists the tab with the `title`
+    /// when it is hidden.
+    #[must_use]
+    pub fn push_element(
+        mut self,
+        id: TabId,
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl Into<String>,
+    ) -> Self {
+        self.tab_labels
+            .push(Label::Element(label.into(), title.into()));
         self.tab_indices.push(id);
         self
     }
@@ -338,14 +450,14 @@ where
     where
         <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme, Style>>,
     {
-        self.class = (Box::new(style) as StyleFn<'a, Theme, Style>).into();
+        self.class = Rc::new((Box::new(style) as StyleFn<'a, Theme, Style>).into());
         self
     }
 
     /// Sets the class of the input of the [`TabBar`].
     #[must_use]
     pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
-        self.class = class.into();
+        self.class = Rc::new(class.into());
         self
     }
 
@@ -357,18 +469,136 @@ where
     }
 }
 
-impl<'a, Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for TabBar<'a, Message, TabId, Theme, Renderer>
+impl<'a, Message, TabId, Theme, Renderer> TabBar<'a, Message, TabId, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a + Catalog + text::Catalog + button::Catalog + iced::widget::container::Catalog,
+    <Theme as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    <Theme as iced::widget::container::Catalog>::Class<'a>:
+        From<iced::widget::container::StyleFn<'a, Theme>>,
+    TabId: Eq + Clone,
+{
+    /// Adds a button at the end of the overflowing tab strip opening a
+    /// [`DropDown`] menu, which lists the tabs hidden by scrolling and selects
+    /// them when pressed.
+    ///
+    /// This sets the overflow to [`Overflow::Scroll`]. The `tab_bar` feature
+    /// enables the `drop_down` feature for the menu.
+    #[must_use]
+    pub fn overflow_menu(mut self) -> Self {
+        self.overflow = Overflow::Scroll;
+        self.menu_view = Some(Box::new(|content: MenuContent<'a, Message, Theme>| {
+            let class = content.class;
+            let items =
+                content
+                    .items
+                    .into_iter()
+                    .fold(Column::new(), |column, (label, message)| {
+                        let class = Rc::clone(&class);
+                        column.push(
+                            Button::new(menu_label(label, content.icon, content.text))
+                                .on_press(message)
+                                .style(move |theme, status| {
+                                    menu_button_style::<Theme>(theme, &class, status)
+                                }),
+                        )
+                    });
+
+            DropDown::new(
+                Space::new(Length::Fill, Length::Fill),
+                Container::new(items).style(move |theme| menu_style::<Theme>(theme, &class)),
+                content.open,
+            )
+            .width(Length::Shrink)
+            .alignment(drop_down::Alignment::BottomStart)
+            .offset(Offset::new(-content.size, content.size))
+            .into()
+        }));
+        self
+    }
+}
+
+impl<Message, TabId, Theme, Renderer> TabBar<'_, Message, TabId, Theme, Renderer>
 where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: Catalog + text::Catalog,
     TabId: Eq + Clone,
 {
-    fn size(&self) -> Size<Length> {
-        Size::new(self.width, self.height)
+    /// Lays out the tabs of the [`TabBar`] in [`Overflow::Scroll`], scrolling
+    /// them in a strip followed by the buttons scrolling it and opening the
+    /// menu of the hidden tabs if they do not fit.
+    fn layout_overflow(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+        node: Node,
+    ) -> Node {
+        let tab_width = match self.tab_width {
+            Length::Fill | Length::FillPortion(_) => Length::Shrink,
+            width => width,
+        };
+        let element: Element<Message, Theme, Renderer> =
+            Element::new(self.tab_row(tab_width, Length::Shrink));
+        tree.children[0].diff(element.as_widget());
+
+        let strip = element.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, Size::new(f32::INFINITY, limits.max().height)),
+        );
+
+        let available = limits.width(self.width).max().width;
+        let state = tree.state.downcast_mut::<State>();
+
+        if strip.size().width <= available {
+            state.reset_scroll();
+            return node;
+        }
+
+        let size = strip.size().height;
+        let controls: u16 = if self.menu_view.is_some() { 3 } else { 2 };
+        let viewport = (available - size * f32::from(controls)).max(0.0);
+        let active = strip
+            .children()
+            .get(self.active_tab)
+            .map_or((0.0, 0.0), |tab| {
+                let bounds = tab.bounds();
+                (bounds.x, bounds.x + bounds.width)
+            });
+        state.fit_scroll(strip.size().width, viewport, self.active_tab, active);
+
+        let offset = Vector::new(-state.scroll_offset, 0.0);
+        let tabs = strip
+            .children()
+            .iter()
+            .map(|tab| tab.clone().translate(offset));
+        let controls = (0..controls).map(|i| {
+            Node::new(Size::new(size, size))
+                .move_to(Point::new(viewport + size * f32::from(i), 0.0))
+        });
+
+        Node::with_children(Size::new(available, size), tabs.chain(controls).collect())
     }
 
-    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+    /// Returns the bounds of the strip showing the tabs of the [`TabBar`],
+    /// which ends at the scroll buttons while the tabs overflow.
+    fn strip_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        let bounds = layout.bounds();
+
+        layout
+            .children()
+            .nth(self.tab_labels.len())
+            .map_or(bounds, |control| Rectangle {
+                width: control.bounds().x - bounds.x,
+                ..bounds
+            })
+    }
+
+    /// Builds the row of the tabs used to lay out the [`TabBar`], with the given
+    /// width of the tabs and of the row.
+    fn tab_row(&self, tab_width: Length, width: Length) -> Row<'_, Message, Theme, Renderer> {
         fn layout_icon<Theme, Renderer>(
             icon: &char,
             size: f32,
@@ -406,23 +636,26 @@ where
                 .width(Length::Shrink)
         }
 
-        let row = self
-            .tab_labels
+        self.tab_labels
             .iter()
             .fold(Row::<Message, Theme, Renderer>::new(), |row, tab_label| {
                 let mut label_row = Row::new()
                     .push(
                         match tab_label {
-                            TabLabel::Icon(icon) => Column::new()
+                            Label::Element(element, _) => Column::new()
+                                .align_x(Alignment::Center)
+                                .push(Borrowed::new(element)),
+
+                            Label::Tab(TabLabel::Icon(icon)) => Column::new()
                                 .align_x(Alignment::Center)
                                 .push(layout_icon(icon, self.icon_size + 1.0, self.font)),
 
-                            TabLabel::Text(text) => Column::new()
+                            Label::Tab(TabLabel::Text(text)) => Column::new()
                                 .padding(5.0)
                                 .align_x(Alignment::Center)
                                 .push(layout_text(text, self.text_size + 1.0, self.text_font)),
 
-                            TabLabel::IconText(icon, text) => {
+                            Label::Tab(TabLabel::IconText(icon, text)) => {
                                 let mut column = Column::new().align_x(Alignment::Center);
 
                                 match self.position {
@@ -489,12 +722,12 @@ where
                                 column
                             }
                         }
-                        .width(self.tab_width)
+                        .width(tab_width)
                         .height(self.height),
                     )
                     .align_y(Alignment::Center)
                     .padding(self.padding)
-                    .width(self.tab_width);
+                    .width(tab_width);
 
                 if self.on_close.is_some() {
                     label_row = label_row.push(
@@ -507,12 +740,35 @@ where
 
                 row.push(label_row)
             })
-            .width(self.width)
+            .width(width)
             .height(self.height)
             .spacing(self.spacing)
-            .align_y(Alignment::Center);
+            .align_y(Alignment::Center)
+    }
+}
+
+impl<'a, Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TabBar<'a, Message, TabId, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: Catalog + text::Catalog,
+    TabId: Eq + Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
 
-        let element: Element<Message, Theme, Renderer> = Element::new(row);
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let element: Element<Message, Theme, Renderer> =
+            Element::new(self.tab_row(self.tab_width, self.width));
         let tab_tree = if let Some(child_tree) = tree.children.get_mut(0) {
             child_tree.diff(element.as_widget());
             child_tree
@@ -522,70 +778,224 @@ where
             &mut tree.children[0]
         };
 
-        element
+        let node = element
             .as_widget()
-            .layout(tab_tree, renderer, &limits.loose())
+            .layout(tab_tree, renderer, &limits.loose());
+
+        match self.overflow {
+            Overflow::Shrink => node,
+            Overflow::Scroll => self.layout_overflow(tree, renderer, limits, node),
+        }
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
+        let strip = self.strip_bounds(layout);
+        let label_cursor = if cursor.is_over(strip) {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+
+        for (i, (tab_label, tab)) in self
+            .tab_labels
+            .iter_mut()
+            .zip(layout.children())
+            .enumerate()
+        {
+            if let Label::Element(element, _) = tab_label {
+                let status = element.as_widget_mut().on_event(
+                    label::tree_mut(state, i),
+                    event.clone(),
+                    label::layout(tab),
+                    label_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+
+                if status == event::Status::Captured {
+                    return status;
+                }
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor
-                    .position()
-                    .map_or(false, |pos| layout.bounds().contains(pos))
-                {
+                let control = layout
+                    .children()
+                    .skip(self.tab_labels.len())
+                    .position(|control| cursor.is_over(control.bounds()));
+
+                if let Some(control) = control {
+                    let state = state.state.downcast_mut::<State>();
+
+                    if control < 2 {
+                        let direction = if control == 0 { -1.0 } else { 1.0 };
+                        let _ = state.scroll_by(direction * strip.width / 2.0);
+                        shell.invalidate_layout();
+                    } else {
+                        state.menu_open = !state.menu_open;
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                    return event::Status::Captured;
+                }
+
+                if cursor.is_over(strip) {
                     let tabs_map: Vec<bool> = layout
                         .children()
-                        .map(|layout| {
-                            cursor
-                                .position()
-                                .map_or(false, |pos| layout.bounds().contains(pos))
-                        })
+                        .take(self.tab_labels.len())
+                        .map(|layout| cursor.is_over(layout.bounds()))
                         .collect();
 
                     if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
-                        shell.publish(
-                            self.on_close
-                                .as_ref()
-                                .filter(|_on_close| {
-                                    let tab_layout = layout.children().nth(new_selected).expect("widget: Layout should have a tab layout at the selected index");
-                                    let cross_layout = tab_layout.children().nth(1).expect("widget: Layout should have a close layout");
-
-                                    cursor.position().map_or(false, |pos| cross_layout.bounds().contains(pos) )
-                                })
-                                .map_or_else(
-                                    || (self.on_select)(self.tab_indices[new_selected].clone()),
-                                    |on_close| (on_close)(self.tab_indices[new_selected].clone()),
-                                ),
-                        );
+                        let on_close = self.on_close.as_ref().filter(|_on_close| {
+                            let tab_layout = layout.children().nth(new_selected).expect(
+                                "widget: Layout should have a tab layout at the selected index",
+                            );
+                            let cross_layout = tab_layout
+                                .children()
+                                .nth(1)
+                                .expect("widget: Layout should have a close layout");
+
+                            cursor.is_over(cross_layout.bounds())
+                        });
+
+                        if let (None, Some(_), Some(position)) =
+                            (on_close, &self.on_reorder, cursor.position())
+                        {
+                            state
+                                .state
+                                .downcast_mut::<State>()
+                                .press(new_selected, position);
+                        }
+
+                        shell.publish(on_close.map_or_else(
+                            || (self.on_select)(self.tab_indices[new_selected].clone()),
+                            |on_close| (on_close)(self.tab_indices[new_selected].clone()),
+                        ));
                         return event::Status::Captured;
                     }
                 }
                 event::Status::Ignored
             }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let tabs: Vec<Rectangle> = layout
+                    .children()
+                    .take(self.tab_labels.len())
+                    .map(|tab| tab.bounds())
+                    .collect();
+
+                if state.state.downcast_mut::<State>().drag(position, &tabs) {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(strip) => {
+                let delta = match delta {
+                    ScrollDelta::Lines { x, y } => -(x + y) * SCROLL_LINE,
+                    ScrollDelta::Pixels { x, y } => -(x + y),
+                };
+
+                if state.state.downcast_mut::<State>().scroll_by(delta) {
+                    shell.invalidate_layout();
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(Named::Escape),
+                ..
+            }) => {
+                let state = state.state.downcast_mut::<State>();
+
+                if std::mem::take(&mut state.menu_open) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let state = state.state.downcast_mut::<State>();
+                let over_menu = layout
+                    .children()
+                    .nth(self.tab_labels.len() + 2)
+                    .is_some_and(|menu| cursor.is_over(menu.bounds()));
+
+                if state.menu_open && !over_menu {
+                    state.menu_open = false;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                let moved = state.release();
+
+                match (moved, &self.on_reorder) {
+                    (Some((from, to)), Some(on_reorder)) => {
+                        shell.publish(on_reorder(self.tab_indices[from].clone(), to));
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
             _ => event::Status::Ignored,
         }
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
+        if state.state.downcast_ref::<State>().is_dragging() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if !cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::default();
+        }
+
+        let strip = self.strip_bounds(layout);
+        let label_interaction = self
+            .tab_labels
+            .iter()
+            .zip(layout.children())
+            .enumerate()
+            .filter_map(|(i, (tab_label, tab))| match tab_label {
+                Label::Element(element, _) if cursor.is_over(strip) => {
+                    Some(element.as_widget().mouse_interaction(
+                        label::tree(state, i),
+                        label::layout(tab),
+                        cursor,
+                        viewport,
+                        renderer,
+                    ))
+                }
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default();
+
+        if label_interaction != mouse::Interaction::default() {
+            return label_interaction;
+        }
+
         let children = layout.children();
         let mut mouse_interaction = mouse::Interaction::default();
 
@@ -609,7 +1019,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        state: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
@@ -643,23 +1053,111 @@ where
             );
         }
 
-        for ((i, tab), layout) in self.tab_labels.iter().enumerate().zip(children) {
-            draw_tab(
+        let strip = self.strip_bounds(layout);
+
+        renderer.with_layer(strip, |renderer| {
+            for ((i, tab), layout) in self.tab_labels.iter().enumerate().zip(children) {
+                draw_tab(
+                    renderer,
+                    tab,
+                    label::tree(state, i),
+                    layout,
+                    self.position,
+                    theme,
+                    &self.class,
+                    i == self.get_active_tab_idx(),
+                    cursor,
+                    (self.font.unwrap_or(REQUIRED_FONT), self.icon_size),
+                    (self.text_font.unwrap_or_default(), self.text_size),
+                    self.close_size,
+                    viewport,
+                );
+            }
+
+            let tabs: Vec<Rectangle> = layout
+                .children()
+                .take(self.tab_labels.len())
+                .map(|tab| tab.bounds())
+                .collect();
+            if let Some(x) = state.state.downcast_ref::<State>().drop_indicator(&tabs) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x - DROP_INDICATOR_WIDTH / 2.0,
+                            y: bounds.y,
+                            width: DROP_INDICATOR_WIDTH,
+                            height: bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    tab_bar::Catalog::style(theme, &self.class, Status::Active).text_color,
+                );
+            }
+        });
+
+        let controls = layout.children().skip(self.tab_labels.len());
+        for (control, icon) in controls.zip(CONTROL_ICONS) {
+            draw_control(
                 renderer,
-                tab,
-                layout,
-                self.position,
+                icon,
+                control,
                 theme,
                 &self.class,
-                i == self.get_active_tab_idx(),
                 cursor,
-                (self.font.unwrap_or(REQUIRED_FONT), self.icon_size),
-                (self.text_font.unwrap_or_default(), self.text_size),
-                self.close_size,
-                viewport,
+                self.icon_size,
             );
         }
     }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let menu_view = self.menu_view.as_ref()?;
+        let menu_layout = layout.children().nth(self.tab_labels.len() + 2)?;
+        let strip = self.strip_bounds(layout);
+
+        let items = layout
+            .children()
+            .zip(self.tab_labels.iter().zip(&self.tab_indices))
+            .filter(|(tab, _)| {
+                let tab = tab.bounds();
+                tab.x < strip.x - 0.5 || tab.x + tab.width > strip.x + strip.width + 0.5
+            })
+            .map(|(_, (label, id))| {
+                let label = match label {
+                    Label::Tab(label) => label.clone(),
+                    Label::Element(_, title) => TabLabel::Text(title.clone()),
+                };
+                (label, (self.on_select)(id.clone()))
+            })
+            .collect();
+
+        let menu = menu_view(MenuContent {
+            items,
+            class: Rc::clone(&self.class),
+            open: state.state.downcast_ref::<State>().menu_open,
+            icon: (self.font.unwrap_or(REQUIRED_FONT), self.icon_size),
+            text: (self.text_font.unwrap_or_default(), self.text_size),
+            size: menu_layout.bounds().height,
+        });
+
+        if let Some(menu_tree) = state.children.get_mut(1) {
+            menu_tree.diff(menu.as_widget());
+        } else {
+            state.children.push(Tree::new(menu.as_widget()));
+        }
+
+        self.menu.insert(menu).as_widget_mut().overlay(
+            &mut state.children[1],
+            menu_layout,
+            renderer,
+            translation,
+        )
+    }
 }
 
 /// Draws a tab.
@@ -668,9 +1166,10 @@ where
     clippy::too_many_lines,
     clippy::too_many_arguments
 )]
-fn draw_tab<Theme, Renderer>(
+fn draw_tab<Message, Theme, Renderer>(
     renderer: &mut Renderer,
-    tab: &TabLabel,
+    tab: &Label<'_, Message, Theme, Renderer>,
+    tree: &Tree,
     layout: Layout<'_>,
     position: Position,
     theme: &Theme,
@@ -728,7 +1227,23 @@ fn draw_tab<Theme, Renderer>(
     }
 
     match tab {
-        TabLabel::Icon(icon) => {
+        Label::Element(element, _) => {
+            element.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color,
+                },
+                label_layout_children
+                    .next()
+                    .expect("Graphics: Layout should have an element layout"),
+                cursor,
+                viewport,
+            );
+        }
+
+        Label::Tab(TabLabel::Icon(icon)) => {
             let icon_bounds = icon_bound_rectangle(label_layout_children.next());
 
             renderer.fill_text(
@@ -749,7 +1264,7 @@ fn draw_tab<Theme, Renderer>(
             );
         }
 
-        TabLabel::Text(text) => {
+        Label::Tab(TabLabel::Text(text)) => {
             let text_bounds = text_bound_rectangle(label_layout_children.next());
 
             renderer.fill_text(
@@ -769,7 +1284,7 @@ fn draw_tab<Theme, Renderer>(
                 text_bounds,
             );
         }
-        TabLabel::IconText(icon, text) => {
+        Label::Tab(TabLabel::IconText(icon, text)) => {
             let icon_bounds: Rectangle;
             let text_bounds: Rectangle;
 
@@ -876,6 +1391,122 @@ fn draw_tab<Theme, Renderer>(
     };
 }
 
+/// Draws a button scrolling the overflowing tab strip or opening the menu of
+/// the hidden tabs.
+fn draw_control<Theme, Renderer>(
+    renderer: &mut Renderer,
+    icon: RequiredIcons,
+    layout: Layout<'_>,
+    theme: &Theme,
+    class: &<Theme as Catalog>::Class<'_>,
+    cursor: Cursor,
+    icon_size: f32,
+) where
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: Catalog,
+{
+    let bounds = layout.bounds();
+    let style = if cursor.is_over(bounds) {
+        tab_bar::Catalog::style(theme, class, Status::Hovered)
+    } else {
+        tab_bar::Catalog::style(theme, class, Status::Disabled)
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius: (0.0).into(),
+                width: style.tab_label_border_width,
+                color: style.tab_label_border_color,
+            },
+            shadow: Shadow::default(),
+        },
+        style.tab_label_background,
+    );
+
+    renderer.fill_text(
+        iced::advanced::text::Text {
+            content: icon_to_string(icon),
+            bounds: Size::new(bounds.width, bounds.height),
+            size: Pixels(icon_size),
+            font: REQUIRED_FONT,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            line_height: LineHeight::Relative(1.3),
+            shaping: iced::advanced::text::Shaping::Advanced,
+            wrapping: Wrapping::default(),
+        },
+        Point::new(bounds.center_x(), bounds.center_y()),
+        style.icon_color,
+        bounds,
+    );
+}
+
+/// Builds the label of a hidden tab in the menu of a [`TabBar`].
+fn menu_label<'a, Message, Theme, Renderer>(
+    label: TabLabel,
+    (icon_font, icon_size): (Font, f32),
+    (text_font, text_size): (Font, f32),
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a + text::Catalog,
+{
+    let icon = |icon: char| Text::new(icon.to_string()).font(icon_font).size(icon_size);
+    let text = |text: String| Text::new(text).font(text_font).size(text_size);
+
+    match label {
+        TabLabel::Icon(icon_char) => icon(icon_char).into(),
+        TabLabel::Text(text_string) => text(text_string).into(),
+        TabLabel::IconText(icon_char, text_string) => Row::new()
+            .push(icon(icon_char))
+            .push(text(text_string))
+            .spacing(5.0)
+            .align_y(Alignment::Center)
+            .into(),
+    }
+}
+
+/// The style of the buttons selecting the hidden tabs in the menu of a
+/// [`TabBar`].
+fn menu_button_style<Theme: Catalog>(
+    theme: &Theme,
+    class: &<Theme as Catalog>::Class<'_>,
+    status: button::Status,
+) -> button::Style {
+    let status = match status {
+        button::Status::Hovered | button::Status::Pressed => Status::Hovered,
+        button::Status::Active | button::Status::Disabled => Status::Disabled,
+    };
+    let style = tab_bar::Catalog::style(theme, class, status);
+
+    button::Style {
+        background: Some(style.tab_label_background),
+        text_color: style.text_color,
+        ..button::Style::default()
+    }
+}
+
+/// The style of the menu listing the hidden tabs of a [`TabBar`].
+fn menu_style<Theme: Catalog>(
+    theme: &Theme,
+    class: &<Theme as Catalog>::Class<'_>,
+) -> iced::widget::container::Style {
+    let style = tab_bar::Catalog::style(theme, class, Status::Disabled);
+
+    iced::widget::container::Style {
+        background: Some(style.tab_label_background),
+        border: Border {
+            radius: (0.0).into(),
+            width: style.tab_label_border_width,
+            color: style.tab_label_border_color,
+        },
+        ..iced::widget::container::Style::default()
+    }
+}
+
 impl<'a, Message, TabId, Theme, Renderer> From<TabBar<'a, Message, TabId, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -0,0 +1,120 @@
+//! The labels of the tabs of a [`TabBar`](super::TabBar).
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::{tree, Tree},
+        Layout, Widget,
+    },
+    mouse::Cursor,
+    Element, Length, Rectangle, Size,
+};
+
+use super::TabLabel;
+
+/// The label of a tab, either a [`TabLabel`] drawn by the
+/// [`TabBar`](super::TabBar) or any element.
+pub(super) enum Label<'a, Message, Theme, Renderer> {
+    /// A label showing an icon and/or a text.
+    Tab(TabLabel),
+    /// A label showing any element, listed with the title in the menu of
+    /// the hidden tabs.
+    Element(Element<'a, Message, Theme, Renderer>, String),
+}
+
+/// Returns the tree of the label of the tab at `index` in the tree of a
+/// [`TabBar`](super::TabBar).
+pub(super) fn tree(tree: &Tree, index: usize) -> &Tree {
+    &tree.children[0].children[index].children[0].children[0]
+}
+
+/// Returns the mutable tree of the label of the tab at `index` in the tree of
+/// a [`TabBar`](super::TabBar).
+pub(super) fn tree_mut(tree: &mut Tree, index: usize) -> &mut Tree {
+    &mut tree.children[0].children[index].children[0].children[0]
+}
+
+/// Returns the layout of the label in the layout of its tab.
+pub(super) fn layout(tab: Layout<'_>) -> Layout<'_> {
+    tab.children()
+        .next()
+        .and_then(|label| label.children().next())
+        .expect("widget: Layout should have a label layout")
+}
+
+/// An element label borrowed to be laid out among the tabs of a
+/// [`TabBar`](super::TabBar), which handles its events and draws it.
+pub(super) struct Borrowed<'a, 'b, Message, Theme, Renderer> {
+    /// The borrowed element.
+    element: &'b Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Borrowed<'a, 'b, Message, Theme, Renderer> {
+    /// Borrows the element to lay it out.
+    pub(super) fn new(element: &'b Element<'a, Message, Theme, Renderer>) -> Self {
+        Self { element }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Borrowed<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.element.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.element.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.element.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.element.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.element.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.element.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.element.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.element
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> From<Borrowed<'a, 'b, Message, Theme, Renderer>>
+    for Element<'b, Message, Theme, Renderer>
+where
+    'a: 'b,
+    Message: 'b,
+    Theme: 'b,
+    Renderer: 'b + renderer::Renderer,
+{
+    fn from(borrowed: Borrowed<'a, 'b, Message, Theme, Renderer>) -> Self {
+        Element::new(borrowed)
+    }
+}
@@ -0,0 +1,202 @@
+//! The state of a [`TabBar`](super::TabBar).
+
+use iced::{Point, Rectangle};
+
+/// The distance, in pixels, the cursor has to move while pressing a tab
+/// before it is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The state of a [`TabBar`](super::TabBar), tracking the tab that is
+/// pressed and dragged to a new position and the scrolling of an
+/// overflowing tab strip.
+#[derive(Clone, Debug, Default)]
+pub(super) struct State {
+    /// The index of the pressed tab and the cursor position it was pressed at.
+    pressed: Option<(usize, Point)>,
+
+    /// Whether the pressed tab is being dragged.
+    dragging: bool,
+
+    /// The index between the tabs the dragged tab would be dropped at.
+    drop_idx: usize,
+
+    /// How far the overflowing tab strip is scrolled.
+    pub(super) scroll_offset: f32,
+
+    /// How far the overflowing tab strip can be scrolled.
+    max_scroll: f32,
+
+    /// The index of the active tab the last time it was scrolled into view.
+    revealed_tab: Option<usize>,
+
+    /// Whether the menu listing the hidden tabs is open.
+    pub(super) menu_open: bool,
+}
+
+impl State {
+    /// Starts tracking the tab at `index` pressed at the given position.
+    pub(super) fn press(&mut self, index: usize, position: Point) {
+        self.pressed = Some((index, position));
+        self.dragging = false;
+    }
+
+    /// Updates the drop position for the cursor moved to `position` over the
+    /// `tabs`, returning whether a tab is being dragged.
+    pub(super) fn drag(&mut self, position: Point, tabs: &[Rectangle]) -> bool {
+        let Some((_, start)) = self.pressed else {
+            return false;
+        };
+
+        if !self.dragging && start.distance(position) > DRAG_THRESHOLD {
+            self.dragging = true;
+        }
+
+        if self.dragging {
+            self.drop_idx = drop_index(tabs, position.x);
+        }
+
+        self.dragging
+    }
+
+    /// Stops tracking the pressed tab, returning its index and its new index
+    /// if it was dragged to a new position.
+    pub(super) fn release(&mut self) -> Option<(usize, usize)> {
+        let (from, _) = self.pressed.take()?;
+
+        if !std::mem::take(&mut self.dragging) {
+            return None;
+        }
+
+        let to = moved_index(from, self.drop_idx);
+        (to != from).then_some((from, to))
+    }
+
+    /// Returns whether a tab is being dragged.
+    pub(super) fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Returns the x position of the insertion indicator between the `tabs`
+    /// while a tab is dragged to a new position.
+    pub(super) fn drop_indicator(&self, tabs: &[Rectangle]) -> Option<f32> {
+        let (from, _) = self.pressed?;
+
+        if !self.dragging || moved_index(from, self.drop_idx) == from {
+            return None;
+        }
+
+        if self.drop_idx == 0 {
+            tabs.first().map(|tab| tab.x)
+        } else {
+            tabs.get(self.drop_idx - 1).map(|tab| tab.x + tab.width)
+        }
+    }
+
+    /// Updates the scrolling for a tab strip of the given width shown in a
+    /// viewport of the given width, scrolling the active tab spanning from
+    /// `active_start` to `active_end` into view when it changed.
+    pub(super) fn fit_scroll(
+        &mut self,
+        strip_width: f32,
+        viewport_width: f32,
+        active: usize,
+        (active_start, active_end): (f32, f32),
+    ) {
+        self.max_scroll = (strip_width - viewport_width).max(0.0);
+
+        if self.revealed_tab != Some(active) {
+            self.revealed_tab = Some(active);
+            self.scroll_offset = self
+                .scroll_offset
+                .max(active_end - viewport_width)
+                .min(active_start);
+        }
+
+        self.scroll_offset = self.scroll_offset.clamp(0.0, self.max_scroll);
+    }
+
+    /// Resets the scrolling of a tab strip fitting its viewport.
+    pub(super) fn reset_scroll(&mut self) {
+        self.scroll_offset = 0.0;
+        self.max_scroll = 0.0;
+        self.revealed_tab = None;
+        self.menu_open = false;
+    }
+
+    /// Scrolls the tab strip by `delta` pixels, returning whether it moved.
+    pub(super) fn scroll_by(&mut self, delta: f32) -> bool {
+        let offset = (self.scroll_offset + delta).clamp(0.0, self.max_scroll);
+        let moved = (offset - self.scroll_offset).abs() > f32::EPSILON;
+        self.scroll_offset = offset;
+        moved
+    }
+}
+
+/// Returns the index between the `tabs` the cursor at `x` drops a tab at.
+fn drop_index(tabs: &[Rectangle], x: f32) -> usize {
+    tabs.iter().filter(|tab| tab.center_x() < x).count()
+}
+
+/// Returns the index of the tab at `from` after dropping it at `drop_idx`.
+fn moved_index(from: usize, drop_idx: usize) -> usize {
+    if drop_idx > from {
+        drop_idx - 1
+    } else {
+        drop_idx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+    use iced::{Point, Rectangle};
+
+    #[test]
+    fn drag_test() {
+        let tabs = [
+            Rectangle::new(Point::new(0.0, 0.0), [100.0, 30.0].into()),
+            Rectangle::new(Point::new(100.0, 0.0), [80.0, 30.0].into()),
+            Rectangle::new(Point::new(180.0, 0.0), [120.0, 30.0].into()),
+        ];
+        let mut state = State::default();
+
+        state.press(0, Point::new(50.0, 15.0));
+        assert!(!state.drag(Point::new(52.0, 15.0), &tabs));
+        assert_eq!(state.release(), None);
+
+        state.press(0, Point::new(50.0, 15.0));
+        assert!(state.drag(Point::new(250.0, 15.0), &tabs));
+        assert_eq!(state.drop_indicator(&tabs), Some(300.0));
+        assert_eq!(state.release(), Some((0, 2)));
+
+        state.press(2, Point::new(250.0, 15.0));
+        assert!(state.drag(Point::new(120.0, 15.0), &tabs));
+        assert_eq!(state.drop_indicator(&tabs), Some(100.0));
+        assert_eq!(state.release(), Some((2, 1)));
+
+        state.press(1, Point::new(140.0, 15.0));
+        assert!(state.drag(Point::new(160.0, 15.0), &tabs));
+        assert_eq!(state.drop_indicator(&tabs), None);
+        assert_eq!(state.release(), None);
+    }
+
+    #[test]
+    fn scroll_test() {
+        let mut state = State::default();
+
+        state.fit_scroll(500.0, 200.0, 3, (350.0, 450.0));
+        assert_eq!(state.scroll_offset, 250.0);
+
+        assert!(state.scroll_by(100.0));
+        assert_eq!(state.scroll_offset, 300.0);
+        assert!(!state.scroll_by(100.0));
+
+        state.fit_scroll(500.0, 200.0, 0, (0.0, 100.0));
+        assert_eq!(state.scroll_offset, 0.0);
+        assert!(!state.scroll_by(-50.0));
+
+        let _ = state.scroll_by(150.0);
+        state.fit_scroll(500.0, 400.0, 0, (0.0, 100.0));
+        assert_eq!(state.scroll_offset, 100.0);
+    }
+}
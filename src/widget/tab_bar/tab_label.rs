@@ -4,6 +4,9 @@
 
 /// A [`TabLabel`] showing an icon and/or a text on a tab
 /// on a [`TabBar`](super::TabBar).
+///
+/// Any other element can label a tab with
+/// [`TabBar::push_element`](super::TabBar::push_element).
 #[allow(missing_debug_implementations)]
 #[derive(Clone, Hash)]
 pub enum TabLabel {
@@ -15,5 +18,4 @@ pub enum TabLabel {
 
     /// A [`TabLabel`] showing an icon and a text on the tab.
     IconText(char, String),
-    // TODO: Support any element as a label.
 }
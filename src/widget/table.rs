@@ -1,20 +1,35 @@
 #![allow(clippy::doc_markdown)]
 
-//! A [`table`] widget for displaying [`TableRow`]s and [`TableHeader`]s
+//! A [`Table`] widget for displaying data using [`TableRow`]s and a [`TableHeader`]
 //!
-//! TODO
+//! The [`Table`] is built from a list of [`TableColumn`]s and a slice of data.
+//! The loose building blocks [`TableRow`] and [`TableHeader`] can be used on
+//! their own as well.
 //!
-//! 
+//! *This API requires the following crate features to be activated: `table`*
 
+mod column;
+mod edit;
+mod export;
+mod filter;
+mod group;
+mod layout;
+mod selection;
 mod table_row;
 mod table_header;
+mod widget;
 
-pub use crate::style::table_header::{Appearance as TableHeaderAppearance, StyleSheet as TableHeaderStyleSheet};
-pub use crate::style::table_row::{Appearance as TableRowAppearance, StyleSheet as TableRowStyleSheet};
-pub use table_header::TableHeaderState;
+pub use crate::style::table_header::{Catalog as TableHeaderCatalog, Style as TableHeaderStyle};
+pub use crate::style::table_row::{Catalog as TableRowCatalog, RowStyleFn, Style as TableRowStyle};
+pub use column::TableColumn;
+pub use edit::CellEdit;
+pub use export::{CopyMode, ExportFormat};
+pub use filter::FilterValue;
+pub use table_header::{ResizeEvent, ResizeState, SortDirection, TableHeaderState};
+pub use widget::Table;
 
 /// A selectable table row
-pub type TableRow<'a, Message, Theme, Renderer> = table_row::TableRow<'a, Message, Theme, Renderer>;
+pub type TableRow<'a, Message, Theme, Renderer, K = usize> = table_row::TableRow<'a, Message, Theme, Renderer, K>;
 
 /// A table header
 pub type TableHeader<'a, Message, Theme, Renderer> = table_header::TableHeader<'a, Message, Theme, Renderer>;
@@ -0,0 +1,223 @@
+//! The column model of a [`Table`](super::Table).
+//!
+//! *This API requires the following crate features to be activated: `table`*
+
+#[cfg(feature = "date_picker")]
+use iced::widget::{button, container, text, Button, Text};
+use iced::{
+    widget::{text_input, TextInput},
+    Element, Length,
+};
+#[cfg(feature = "number_input")]
+use std::ops::RangeBounds;
+
+use super::filter::FilterValue;
+#[cfg(feature = "date_picker")]
+use crate::{core::date::Date, style::date_picker, widget::date_picker::DatePicker};
+#[cfg(feature = "number_input")]
+use crate::{style::number_input, widget::number_input::NumberInput};
+
+/// Produces an element for an entry of the table data.
+type CellFn<'a, T, Message, Theme, Renderer> =
+    Box<dyn Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// Produces the text of a cell for an entry of the table data.
+type TextFn<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+/// Produces an element summarizing the entries of a group of rows.
+type AggregateFn<'a, T, Message, Theme, Renderer> =
+    Box<dyn Fn(&[&'a T]) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// A column of a [`Table`](super::Table).
+///
+/// A column has a title shown in the header, a width and a closure producing
+/// the cell content for every entry of the table data.
+#[allow(missing_debug_implementations)]
+pub struct TableColumn<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The title of the column shown in the header.
+    pub(super) title: String,
+    /// The width of the column.
+    pub(super) width: Length,
+    /// Produces the content of a cell of the column.
+    pub(super) view: CellFn<'a, T, Message, Theme, Renderer>,
+    /// Produces the editor replacing a cell of the column while it is edited.
+    pub(super) editor: Option<CellFn<'a, T, Message, Theme, Renderer>>,
+    /// Produces the content of the cell of the column in a group header row.
+    pub(super) aggregate: Option<AggregateFn<'a, T, Message, Theme, Renderer>>,
+    /// The filter of the column shown in the filter row of the table.
+    pub(super) filter: Option<Element<'a, FilterValue, Theme, Renderer>>,
+    /// Produces the text of a cell of the column when the table is exported.
+    pub(super) export: Option<TextFn<'a, T>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> TableColumn<'a, T, Message, Theme, Renderer> {
+    /// Creates a new [`TableColumn`] with the given title and a closure
+    /// producing the content of its cells.
+    pub fn new<F, E>(title: impl Into<String>, view: F) -> Self
+    where
+        F: Fn(&'a T) -> E + 'a,
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            title: title.into(),
+            width: Length::Fill,
+            view: Box::new(move |entry| view(entry).into()),
+            editor: None,
+            aggregate: None,
+            filter: None,
+            export: None,
+        }
+    }
+
+    /// Sets the width of the [`TableColumn`].
+    ///
+    /// Fixed widths are kept as is, the remaining space of the table is
+    /// distributed among the fill portions of the other columns.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the closure producing the editor of the cells of the
+    /// [`TableColumn`], like a [`TypedInput`](crate::TypedInput) or a
+    /// [`NumberInput`](crate::NumberInput) bound to the edited value.
+    ///
+    /// The editor replaces the cell of the row passed to
+    /// [`Table::editing`](super::Table::editing).
+    #[must_use]
+    pub fn editor<F, E>(mut self, editor: F) -> Self
+    where
+        F: Fn(&'a T) -> E + 'a,
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.editor = Some(Box::new(move |entry| editor(entry).into()));
+        self
+    }
+
+    /// Sets the closure summarizing the entries of a group of rows, like
+    /// their sum or average, shown in the cell of the [`TableColumn`] in the
+    /// header row of the group.
+    ///
+    /// Rows are grouped with [`Table::group_by`](super::Table::group_by).
+    #[must_use]
+    pub fn aggregate<F, E>(mut self, aggregate: F) -> Self
+    where
+        F: Fn(&[&'a T]) -> E + 'a,
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.aggregate = Some(Box::new(move |entries| aggregate(entries).into()));
+        self
+    }
+
+    /// Sets the closure producing the text of the cells of the
+    /// [`TableColumn`] when the table is exported or its selected rows are
+    /// copied to the clipboard.
+    ///
+    /// Columns without a text are left out of the export.
+    #[must_use]
+    pub fn export<F>(mut self, text: F) -> Self
+    where
+        F: Fn(&T) -> String + 'a,
+    {
+        self.export = Some(Box::new(text));
+        self
+    }
+
+    /// Returns whether the cells of the [`TableColumn`] can be edited.
+    #[must_use]
+    pub fn is_editable(&self) -> bool {
+        self.editor.is_some()
+    }
+
+    /// Returns the title of the [`TableColumn`].
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> TableColumn<'a, T, Message, Theme, Renderer>
+where
+    Theme: 'a + text_input::Catalog,
+    Renderer: 'a + iced::advanced::text::Renderer,
+{
+    /// Adds a text filter with the entered `value` to the [`TableColumn`],
+    /// shown in the filter row of the table.
+    ///
+    /// The filter row is shown once
+    /// [`Table::on_filter_change`](super::Table::on_filter_change) is set.
+    #[must_use]
+    pub fn text_filter(mut self, value: &str) -> Self {
+        self.filter = Some(
+            TextInput::new("Filter", value)
+                .on_input(FilterValue::Text)
+                .into(),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "number_input")]
+impl<'a, T, Message, Theme, Renderer> TableColumn<'a, T, Message, Theme, Renderer>
+where
+    Theme: 'a + number_input::ExtendedCatalog,
+    Renderer: 'a + iced::advanced::text::Renderer<Font = iced::Font>,
+{
+    /// Adds a number filter with the entered `value` within `bounds` to the
+    /// [`TableColumn`], shown in the filter row of the table.
+    ///
+    /// *This API requires the following crate features to be activated: `number_input`*
+    #[must_use]
+    pub fn number_filter(mut self, value: f64, bounds: impl RangeBounds<f64>) -> Self {
+        self.filter = Some(
+            NumberInput::new(value, bounds, FilterValue::Number)
+                .width(Length::Fill)
+                .into(),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "date_picker")]
+impl<'a, T, Message, Theme> TableColumn<'a, T, Message, Theme, iced::Renderer>
+where
+    Theme: 'a + date_picker::Catalog + button::Catalog + text::Catalog + container::Catalog,
+{
+    /// Adds a date filter with the picked `date` to the [`TableColumn`],
+    /// shown in the filter row of the table.
+    ///
+    /// Clicking the filter produces a [`FilterValue::Date`] asking to open
+    /// the date picker, which is shown while `picking` is set. Cancelling the
+    /// date picker clears the filter.
+    ///
+    /// *This API requires the following crate features to be activated: `date_picker`*
+    #[must_use]
+    pub fn date_filter(mut self, date: Option<Date>, picking: bool) -> Self {
+        let label = date.map_or_else(|| String::from("Any date"), |date| date.to_string());
+        let underlay = Button::new(Text::new(label))
+            .on_press(FilterValue::Date {
+                date,
+                picking: true,
+            })
+            .width(Length::Fill);
+
+        self.filter = Some(
+            DatePicker::new(
+                picking,
+                date.unwrap_or_default(),
+                underlay,
+                FilterValue::Date {
+                    date: None,
+                    picking: false,
+                },
+                |date| FilterValue::Date {
+                    date: Some(date),
+                    picking: false,
+                },
+            )
+            .into(),
+        );
+        self
+    }
+}
@@ -0,0 +1,56 @@
+//! The in-place editing of the cells of a [`Table`](super::Table).
+//!
+//! *This API requires the following crate features to be activated: `table`*
+
+use iced::{
+    advanced::widget::{
+        operation::{Focusable, Operation},
+        Id,
+    },
+    Rectangle,
+};
+
+/// A step of the editing of a cell of a [`Table`](super::Table).
+///
+/// The edited value is held by the application, like the value of any other
+/// input widget, and is updated by the messages of the editor of the column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CellEdit {
+    /// The cell was double-clicked or Enter was pressed on it, the
+    /// application should start editing it.
+    Start,
+    /// Enter was pressed in the editor or the user clicked outside of it,
+    /// the application should apply its edited value.
+    Commit,
+    /// Escape was pressed in the editor, the application should discard the
+    /// edited value.
+    Cancel,
+}
+
+/// Returns an [`Operation`] focusing the first focusable widget, used to
+/// focus an editor once it replaced its cell.
+pub(super) fn focus_first() -> impl Operation<()> {
+    struct FocusFirst {
+        focused: bool,
+    }
+
+    impl Operation<()> for FocusFirst {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            if !self.focused {
+                state.focus();
+                self.focused = true;
+            }
+        }
+    }
+
+    FocusFirst { focused: false }
+}
@@ -0,0 +1,111 @@
+//! The export of the rows of a [`Table`](super::Table) as delimited text.
+//!
+//! *This API requires the following crate features to be activated: `table`*
+
+use std::borrow::Cow;
+
+/// The format of the text produced by [`Table::export`](super::Table::export).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values.
+    #[default]
+    Csv,
+    /// Tab-separated values, as pasted into spreadsheets.
+    Tsv,
+}
+
+impl ExportFormat {
+    /// Returns the character separating the fields of a record.
+    #[must_use]
+    pub const fn delimiter(self) -> char {
+        match self {
+            Self::Csv => ',',
+            Self::Tsv => '\t',
+        }
+    }
+}
+
+/// What a [`Table`](super::Table) copies to the clipboard when the command
+/// key and C are pressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CopyMode {
+    /// The selected rows, as tab-separated values.
+    #[default]
+    Rows,
+    /// The cell last clicked in the row of the keyboard cursor.
+    Cell,
+}
+
+/// Writes the `records` in the given format, one line per record.
+pub(super) fn write<I, R>(format: ExportFormat, records: I) -> String
+where
+    I: IntoIterator<Item = R>,
+    R: IntoIterator<Item = String>,
+{
+    let delimiter = format.delimiter();
+    let mut text = String::new();
+
+    for record in records {
+        for (idx, field) in record.into_iter().enumerate() {
+            if idx > 0 {
+                text.push(delimiter);
+            }
+            text.push_str(&escape(&field, delimiter));
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Quotes a field containing the delimiter, a quote or a line break, and
+/// doubles the quotes inside of it.
+fn escape(field: &str, delimiter: char) -> Cow<'_, str> {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write, ExportFormat};
+
+    fn records(records: &[&[&str]]) -> Vec<Vec<String>> {
+        records
+            .iter()
+            .map(|record| record.iter().map(|field| (*field).to_owned()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn write_csv_test() {
+        let records = records(&[
+            &["Name", "Note"],
+            &["Alice", "likes, commas"],
+            &["Bob", "says \"hi\""],
+            &["Carol", "two\nlines"],
+        ]);
+
+        assert_eq!(
+            write(ExportFormat::Csv, records),
+            "Name,Note\nAlice,\"likes, commas\"\nBob,\"says \"\"hi\"\"\"\nCarol,\"two\nlines\"\n"
+        );
+    }
+
+    #[test]
+    fn write_tsv_test() {
+        let records = records(&[
+            &["Name", "Note"],
+            &["Alice", "likes, commas"],
+            &["Bob", "a\tb"],
+        ]);
+
+        assert_eq!(
+            write(ExportFormat::Tsv, records),
+            "Name\tNote\nAlice\tlikes, commas\nBob\t\"a\tb\"\n"
+        );
+        assert_eq!(write(ExportFormat::Tsv, Vec::<Vec<String>>::new()), "");
+    }
+}
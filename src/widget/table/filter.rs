@@ -0,0 +1,33 @@
+//! The filtering of the rows of a [`Table`](super::Table).
+//!
+//! *This API requires the following crate features to be activated: `table`*
+
+#[cfg(feature = "date_picker")]
+use crate::core::date::Date;
+
+/// The value of the filter of a column of a [`Table`](super::Table),
+/// produced when the user edits the filter.
+///
+/// Like the sorting, the filtering is left to the application, which passes
+/// the matching entries to the table and the value back to the filter of the
+/// [`TableColumn`](super::TableColumn).
+#[derive(Clone, Debug)]
+pub enum FilterValue {
+    /// The text entered in a text filter.
+    Text(String),
+    /// The number entered in a number filter.
+    ///
+    /// *This variant requires the following crate features to be activated: `number_input`*
+    #[cfg(feature = "number_input")]
+    Number(f64),
+    /// The state of a date filter.
+    ///
+    /// *This variant requires the following crate features to be activated: `date_picker`*
+    #[cfg(feature = "date_picker")]
+    Date {
+        /// The picked date, `None` if the filter was cleared.
+        date: Option<Date>,
+        /// Whether the date picker is open.
+        picking: bool,
+    },
+}
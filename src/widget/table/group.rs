@@ -0,0 +1,223 @@
+//! The grouping and nesting of the rows of a [`Table`](super::Table).
+//!
+//! *This API requires the following crate features to be activated: `table`*
+
+use iced::{
+    advanced::{
+        renderer,
+        text::{self, Text},
+    },
+    alignment::{Horizontal, Vertical},
+    widget::{
+        text::{LineHeight, Shaping, Wrapping},
+        Row, Space,
+    },
+    Alignment, Color, Element, Pixels, Rectangle,
+};
+use iced_fonts::{required::RequiredIcons, REQUIRED_FONT};
+use std::collections::HashMap;
+
+/// The width reserved for the disclosure caret at the left of a group header row.
+pub(super) const DISCLOSURE_WIDTH: f32 = 20.0;
+
+/// The indentation of the first cell of a tree table row per level of depth.
+pub(super) const INDENT_WIDTH: f32 = 16.0;
+
+/// A line of a [`Table`](super::Table) below its header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Line {
+    /// A row displaying the entry at `index`, belonging to the group at
+    /// `group` if the rows are grouped.
+    ///
+    /// In a tree table, `depth` is the nesting level of the node of the row
+    /// and `expanded` is whether it is expanded, `None` for leaves.
+    Entry {
+        index: usize,
+        group: Option<usize>,
+        depth: usize,
+        expanded: Option<bool>,
+    },
+    /// The header row of the group at the index.
+    Group(usize),
+}
+
+/// Groups the indices of `keys` by equal keys, in the order in which the
+/// groups first appear.
+pub(super) fn group_indices(keys: impl IntoIterator<Item = String>) -> Vec<(String, Vec<usize>)> {
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut positions = HashMap::<String, usize>::new();
+
+    for (idx, key) in keys.into_iter().enumerate() {
+        if let Some(&position) = positions.get(&key) {
+            groups[position].1.push(idx);
+        } else {
+            let _ = positions.insert(key.clone(), groups.len());
+            groups.push((key, vec![idx]));
+        }
+    }
+
+    groups
+}
+
+/// Walks the trees of `roots` depth-first, returning every visited node with
+/// its depth and whether it is expanded, `None` for leaves.
+///
+/// Only the children of expanded nodes are visited.
+pub(super) fn flatten<'a, T, C, E>(
+    roots: &'a [T],
+    children: C,
+    is_expanded: E,
+) -> Vec<(&'a T, usize, Option<bool>)>
+where
+    C: Fn(&'a T) -> Option<&'a [T]>,
+    E: Fn(&'a T) -> bool,
+{
+    let mut nodes = Vec::new();
+    let mut stack = vec![(roots.iter(), 0)];
+
+    while let Some((next, depth)) = stack.last_mut().map(|(iter, depth)| (iter.next(), *depth)) {
+        let Some(node) = next else {
+            let _ = stack.pop();
+            continue;
+        };
+
+        let expanded = children(node).map(|children| {
+            let expanded = is_expanded(node);
+            if expanded {
+                stack.push((children.iter(), depth + 1));
+            }
+            expanded
+        });
+
+        nodes.push((node, depth, expanded));
+    }
+
+    nodes
+}
+
+/// Indents the content of the first cell of a tree table row by the `depth`
+/// of its node, leaving space for the disclosure caret.
+pub(super) fn indent<'a, Message, Theme, Renderer>(
+    cell: Element<'a, Message, Theme, Renderer>,
+    depth: usize,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    Row::new()
+        .push(Space::with_width(
+            depth as f32 * INDENT_WIDTH + DISCLOSURE_WIDTH,
+        ))
+        .push(cell)
+        .align_y(Alignment::Center)
+        .into()
+}
+
+/// Returns the bounds of the disclosure caret in the first cell of a row at
+/// the given `depth`.
+pub(super) fn disclosure_bounds(cell: Rectangle, depth: usize) -> Rectangle {
+    Rectangle {
+        x: cell.x + depth as f32 * INDENT_WIDTH,
+        width: DISCLOSURE_WIDTH,
+        ..cell
+    }
+}
+
+/// Draws the caret indicating whether a group or a node is expanded.
+pub(super) fn draw_disclosure<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    expanded: bool,
+    color: Color,
+) where
+    Renderer: text::Renderer<Font = iced::Font>,
+{
+    let icon = if expanded {
+        RequiredIcons::CaretDownFill
+    } else {
+        RequiredIcons::CaretRightFill
+    };
+
+    let size = renderer.default_size();
+
+    renderer.fill_text(
+        Text {
+            content: char::from(icon).to_string(),
+            bounds: bounds.size(),
+            size: Pixels(size.0 * 0.75),
+            font: REQUIRED_FONT,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            line_height: LineHeight::default(),
+            shaping: Shaping::Basic,
+            wrapping: Wrapping::default(),
+        },
+        bounds.center(),
+        color,
+        bounds,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flatten, group_indices};
+
+    #[test]
+    fn group_indices_test() {
+        let keys = ["b", "a", "b", "c", "a"].map(String::from);
+
+        assert_eq!(
+            group_indices(keys),
+            vec![
+                (String::from("b"), vec![0, 2]),
+                (String::from("a"), vec![1, 4]),
+                (String::from("c"), vec![3]),
+            ]
+        );
+        assert!(group_indices(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn flatten_test() {
+        struct Node {
+            name: &'static str,
+            children: Option<Vec<Node>>,
+        }
+
+        let node = |name, children| Node { name, children };
+        let roots = vec![
+            node(
+                "a",
+                Some(vec![
+                    node("a1", None),
+                    node("a2", Some(vec![node("a2i", None)])),
+                ]),
+            ),
+            node("b", Some(vec![node("b1", None)])),
+            node("c", Some(Vec::new())),
+        ];
+
+        let flattened = flatten(
+            &roots,
+            |node| node.children.as_deref(),
+            |node| node.name != "b",
+        )
+        .into_iter()
+        .map(|(node, depth, expanded)| (node.name, depth, expanded))
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            flattened,
+            vec![
+                ("a", 0, Some(true)),
+                ("a1", 1, None),
+                ("a2", 1, Some(true)),
+                ("a2i", 2, None),
+                ("b", 0, Some(false)),
+                ("c", 0, Some(true)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Column width resolution and cell layout shared by the table widgets.
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::Tree,
+    },
+    Element, Length, Size,
+};
+
+/// The minimum width, in pixels, a column can be resized to.
+pub(super) const MIN_COLUMN_WIDTH: f32 = 30.0;
+
+/// Resolves the pixel widths of the columns of a table.
+///
+/// Columns with an `overrides` entry (set when the user resized them) or a
+/// [`Length::Fixed`] width keep their size, the remaining space is distributed
+/// among the fill portions of the other columns.
+///
+/// Without a bounded `available` width, like inside a horizontal scrollable,
+/// there is no space to distribute and the other columns take the width of
+/// their content, measured by `content_width` for a column index.
+pub(super) fn resolve_widths(
+    widths: &[Length],
+    overrides: &[Option<f32>],
+    available: f32,
+    mut content_width: impl FnMut(usize) -> f32,
+) -> Vec<f32> {
+    let fixed = widths
+        .iter()
+        .enumerate()
+        .filter_map(
+            |(i, width)| match (overrides.get(i).copied().flatten(), *width) {
+                (Some(w), _) | (None, Length::Fixed(w)) => Some(w),
+                _ => None,
+            },
+        )
+        .sum::<f32>();
+
+    let portions = widths
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| overrides.get(*i).copied().flatten().is_none())
+        .map(|(_, width)| u32::from(width.fill_factor()))
+        .sum::<u32>();
+
+    let remaining = (available - fixed).max(0.0);
+
+    widths
+        .iter()
+        .enumerate()
+        .map(|(i, width)| {
+            if let Some(w) = overrides.get(i).copied().flatten() {
+                return w;
+            }
+
+            match width {
+                Length::Fixed(w) => *w,
+                _ if !available.is_finite() => content_width(i).max(MIN_COLUMN_WIDTH),
+                _ if portions == 0 => MIN_COLUMN_WIDTH,
+                _ => (remaining * f32::from(width.fill_factor()) / portions as f32)
+                    .max(MIN_COLUMN_WIDTH),
+            }
+        })
+        .collect()
+}
+
+/// Lays out a line of cells using the given column widths.
+///
+/// Every cell is laid out twice: once to find the height of the line and a
+/// second time to stretch all cells to that height, so that cell backgrounds
+/// line up.
+pub(super) fn layout_cells<Message, Theme, Renderer>(
+    cells: &[Element<'_, Message, Theme, Renderer>],
+    trees: &mut [Tree],
+    renderer: &Renderer,
+    widths: &[f32],
+    height: Length,
+) -> Node
+where
+    Renderer: renderer::Renderer,
+{
+    let line_height = match height {
+        Length::Fixed(h) => h,
+        _ => cells
+            .iter()
+            .zip(trees.iter_mut())
+            .zip(widths)
+            .map(|((cell, tree), width)| {
+                let limits = Limits::new(Size::new(*width, 0.0), Size::new(*width, f32::INFINITY));
+                cell.as_widget()
+                    .layout(tree, renderer, &limits)
+                    .size()
+                    .height
+            })
+            .fold(0.0, f32::max),
+    };
+
+    let mut x = 0.0;
+    let children = cells
+        .iter()
+        .zip(trees.iter_mut())
+        .zip(widths)
+        .map(|((cell, tree), width)| {
+            let size = Size::new(*width, line_height);
+            let node = cell
+                .as_widget()
+                .layout(tree, renderer, &Limits::new(size, size))
+                .move_to((x, 0.0));
+            x += width;
+            node
+        })
+        .collect();
+
+    Node::with_children(Size::new(x, line_height), children)
+}
+
+/// Returns the width a cell takes up when it is not constrained.
+///
+/// Cells filling their column have no such width, they return `None`.
+pub(super) fn measure_width<Message, Theme, Renderer>(
+    cell: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    renderer: &Renderer,
+) -> Option<f32>
+where
+    Renderer: renderer::Renderer,
+{
+    let limits = Limits::new(Size::ZERO, Size::INFINITY);
+    let width = cell
+        .as_widget()
+        .layout(tree, renderer, &limits)
+        .size()
+        .width;
+
+    width.is_finite().then_some(width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_widths_test() {
+        let widths = [Length::Fill, Length::Fixed(100.0), Length::FillPortion(2)];
+
+        assert_eq!(
+            resolve_widths(&widths, &[], 400.0, |_| 0.0),
+            vec![100.0, 100.0, 200.0]
+        );
+        assert_eq!(
+            resolve_widths(&widths, &[None, None, Some(50.0)], 400.0, |_| 0.0),
+            vec![250.0, 100.0, 50.0]
+        );
+    }
+
+    #[test]
+    fn resolve_unbounded_widths_test() {
+        let widths = [Length::Fill, Length::Fixed(100.0), Length::FillPortion(2)];
+        let content = [120.0, 500.0, 10.0];
+
+        assert_eq!(
+            resolve_widths(&widths, &[], f32::INFINITY, |i| content[i]),
+            vec![120.0, 100.0, MIN_COLUMN_WIDTH]
+        );
+        assert_eq!(
+            resolve_widths(&widths, &[Some(80.0)], f32::INFINITY, |i| content[i]),
+            vec![80.0, 100.0, MIN_COLUMN_WIDTH]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! The selection model of the rows of a [`Table`](super::Table).
+//!
+//! The anchor and the cursor of the selection are the keys of their rows,
+//! so they stay with their entries when the data is sorted. They are looked
+//! up among the displayed rows, so that ranges follow the order in which the
+//! rows are displayed.
+
+use iced::keyboard::Modifiers;
+
+/// Returns the selection after the row at `index` was clicked.
+///
+/// A plain click selects only the clicked row, holding the command key
+/// toggles it and holding shift selects the range from the row with the key
+/// `anchor` to it.
+pub(super) fn click<K>(
+    ids: &[K],
+    selection: &[K],
+    index: usize,
+    anchor: Option<&K>,
+    modifiers: Modifiers,
+) -> Vec<K>
+where
+    K: Clone + PartialEq,
+{
+    let Some(id) = ids.get(index) else {
+        return selection.to_vec();
+    };
+
+    if modifiers.shift() {
+        let range = range(ids, position(ids, anchor).unwrap_or(index), index);
+
+        if modifiers.command() {
+            let added = range.into_iter().filter(|id| !selection.contains(id));
+            selection.iter().cloned().chain(added).collect()
+        } else {
+            range
+        }
+    } else if modifiers.command() {
+        if selection.contains(id) {
+            selection.iter().filter(|s| *s != id).cloned().collect()
+        } else {
+            let mut selection = selection.to_vec();
+            selection.push(id.clone());
+            selection
+        }
+    } else {
+        vec![id.clone()]
+    }
+}
+
+/// Returns the position of the row next to the row with the key `cursor` and
+/// the selection after moving to it with the arrow keys.
+///
+/// Holding shift extends the selection from the row with the key `anchor` to
+/// the new row.
+pub(super) fn step<K>(
+    ids: &[K],
+    cursor: Option<&K>,
+    anchor: Option<&K>,
+    down: bool,
+    modifiers: Modifiers,
+) -> Option<(usize, Vec<K>)>
+where
+    K: Clone + PartialEq,
+{
+    let last = ids.len().checked_sub(1)?;
+    let next = match position(ids, cursor) {
+        Some(cursor) if down => (cursor + 1).min(last),
+        Some(cursor) => cursor.saturating_sub(1).min(last),
+        None => 0,
+    };
+
+    let selection = if modifiers.shift() {
+        range(ids, position(ids, anchor).unwrap_or(next), next)
+    } else {
+        ids[next..=next].to_vec()
+    };
+
+    Some((next, selection))
+}
+
+/// Returns the position of the row with the key `id` among the displayed rows.
+fn position<K: PartialEq>(ids: &[K], id: Option<&K>) -> Option<usize> {
+    id.and_then(|id| ids.iter().position(|other| other == id))
+}
+
+/// Returns the ids of the rows between `from` and `to`, both included.
+fn range<K: Clone>(ids: &[K], from: usize, to: usize) -> Vec<K> {
+    let (start, end) = if from <= to { (from, to) } else { (to, from) };
+    ids.get(start..=end.min(ids.len().saturating_sub(1)))
+        .map(<[K]>::to_vec)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn click_test() {
+        let ids = [10, 11, 12, 13];
+
+        assert_eq!(
+            click(&ids, &[10], 2, Some(&10), Modifiers::empty()),
+            vec![12]
+        );
+        assert_eq!(
+            click(&ids, &[10], 2, Some(&10), Modifiers::COMMAND),
+            vec![10, 12]
+        );
+        assert_eq!(
+            click(&ids, &[10, 12], 0, Some(&12), Modifiers::COMMAND),
+            vec![12]
+        );
+        assert_eq!(
+            click(&ids, &[11], 3, Some(&11), Modifiers::SHIFT),
+            vec![11, 12, 13]
+        );
+        assert_eq!(
+            click(&ids, &[13], 0, Some(&11), Modifiers::SHIFT),
+            vec![10, 11]
+        );
+
+        // The anchor stays with its row when the rows are sorted.
+        let sorted = [13, 12, 11, 10];
+        assert_eq!(
+            click(&sorted, &[11], 0, Some(&11), Modifiers::SHIFT),
+            vec![13, 12, 11]
+        );
+        assert_eq!(click(&ids, &[], 1, Some(&99), Modifiers::SHIFT), vec![11]);
+    }
+
+    #[test]
+    fn step_test() {
+        let ids = [10, 11, 12];
+
+        assert_eq!(
+            step(&ids, None, None, true, Modifiers::empty()),
+            Some((0, vec![10]))
+        );
+        assert_eq!(
+            step(&ids, Some(&12), Some(&12), true, Modifiers::empty()),
+            Some((2, vec![12]))
+        );
+        assert_eq!(
+            step(&ids, Some(&11), Some(&12), false, Modifiers::SHIFT),
+            Some((0, vec![10, 11, 12]))
+        );
+        assert_eq!(
+            step(&[12, 11, 10], Some(&11), Some(&11), true, Modifiers::SHIFT),
+            Some((2, vec![11, 10]))
+        );
+        assert_eq!(step::<u8>(&[], None, None, true, Modifiers::empty()), None);
+    }
+}
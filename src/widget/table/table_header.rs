@@ -6,39 +6,151 @@ use iced::{
     advanced::{
         layout::{Limits, Node, flex},
         renderer,
-        widget::Tree,
+        text::{self, Text},
+        widget::{tree, Tree},
         Clipboard, Layout, Shell, Widget,
     },
-	widget::Space,
+	alignment::{Horizontal, Vertical},
+	widget::{text::{LineHeight, Shaping, Wrapping}, Row, Space},
     event,
     mouse,
-    Alignment, Background, Border, Color, Element, Event, Length, Padding, Point, Rectangle,
+    Alignment, Background, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle,
     Size
 };
+use iced_fonts::{required::RequiredIcons, REQUIRED_FONT};
+
+use super::layout::{layout_cells, measure_width, MIN_COLUMN_WIDTH};
+
+pub use crate::core::resize::{ResizeEvent, ResizeState};
 
 #[derive(Clone, Debug, Default)]
-/// The state of the [`TableHeader`]
+/// The state of the [`TableHeader`], kept in its widget tree
 pub struct TableHeaderState {
-	/// Whether the cursor is hovering over the resize area
-	pub resize_hovering: bool,
+	/// The state of the dividers between the columns being resized
+	pub resize: ResizeState,
+
+	/// The column whose header cell is pressed and the cursor position it was pressed at
+	pub pressed: Option<(usize, Point)>,
+
+	/// Whether the pressed column is being dragged to a new position
+	pub dragging: bool,
+
+	/// The index between the columns the dragged column would be dropped at
+	pub drop_idx: usize,
+}
+
+impl TableHeaderState {
+	/// Tracks presses, drags and releases on the header `cells` and returns
+	/// the resulting [`ColumnAction`] once the mouse button is released.
+	pub(super) fn update_columns(
+		&mut self,
+		event: &Event,
+		cursor: mouse::Cursor,
+		cells: &[Rectangle],
+	) -> (event::Status, Option<ColumnAction>) {
+		match event {
+			Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+				let Some(position) = cursor.position() else {
+					return (event::Status::Ignored, None);
+				};
+
+				match cells.iter().position(|cell| cell.contains(position)) {
+					Some(column) => {
+						self.pressed = Some((column, position));
+						(event::Status::Captured, None)
+					}
+					None => (event::Status::Ignored, None),
+				}
+			}
+			Event::Mouse(mouse::Event::CursorMoved { position }) => {
+				let Some((_, start)) = self.pressed else {
+					return (event::Status::Ignored, None);
+				};
+
+				if !self.dragging && start.distance(*position) > DRAG_THRESHOLD {
+					self.dragging = true;
+				}
+
+				if self.dragging {
+					self.drop_idx = drop_index(cells, position.x);
+					(event::Status::Captured, None)
+				} else {
+					(event::Status::Ignored, None)
+				}
+			}
+			Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+				let Some((column, _)) = self.pressed.take() else {
+					return (event::Status::Ignored, None);
+				};
+
+				let action = if std::mem::take(&mut self.dragging) {
+					let to = moved_index(column, self.drop_idx);
+					(to != column).then_some(ColumnAction::Move(column, to))
+				} else {
+					cells
+						.get(column)
+						.filter(|cell| cursor.is_over(**cell))
+						.map(|_| ColumnAction::Sort(column))
+				};
+
+				(event::Status::Captured, action)
+			}
+			_ => (event::Status::Ignored, None),
+		}
+	}
+
+	/// Returns the x position of the drop indicator between the header
+	/// `cells` while a column is dragged to a new position.
+	pub(crate) fn drop_indicator(&self, cells: &[Rectangle]) -> Option<f32> {
+		let (column, _) = self.pressed?;
+
+		if !self.dragging || moved_index(column, self.drop_idx) == column {
+			return None;
+		}
+
+		if self.drop_idx == 0 {
+			cells.first().map(|cell| cell.x)
+		} else {
+			cells.get(self.drop_idx - 1).map(|cell| cell.x + cell.width)
+		}
+	}
+}
 
-	/// Whether the [`TableHeader`] is currently resizing
-	pub resizing: bool,
+/// The distance, in pixels, the cursor has to move while pressing a header
+/// cell before its column is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
 
-	/// The starting cursor position when resizing
-	pub starting_cursor_pos: Option<Point>,
+/// The width, in pixels, of the indicator showing where a dragged column is dropped.
+const DROP_INDICATOR_WIDTH: f32 = 2.0;
 
-	/// The starting width of the left column when resizing 
-	pub starting_left_width: f32,
+/// The width, in pixels, reserved for the sort indicator at the end of sortable header cells.
+const SORT_INDICATOR_WIDTH: f32 = 16.0;
 
-	/// The starting width of the right column when resizing
-	pub starting_right_width: f32,
+/// The result of pressing and releasing a header cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ColumnAction {
+	/// The header cell of the column was clicked.
+	Sort(usize),
+	/// The column was dragged from the first to the second index.
+	Move(usize, usize),
+}
+
+/// Returns the index between the `cells` the cursor at `x` drops a column at.
+fn drop_index(cells: &[Rectangle], x: f32) -> usize {
+	cells.iter().filter(|cell| cell.center_x() < x).count()
+}
 
-	/// the index of the column being resized 
-	pub resizing_idx: usize,
+/// Returns the index of the column at `from` after dropping it at `drop_idx`.
+fn moved_index(from: usize, drop_idx: usize) -> usize {
+	if drop_idx > from {
+		drop_idx - 1
+	} else {
+		drop_idx
+	}
 }
 
-pub use crate::style::table_header::StyleSheet;
+use crate::style::status::{Status, StyleFn};
+pub use crate::style::table_header::{Catalog, Style};
 
 /// A table header
 /// 
@@ -72,39 +184,38 @@ pub use crate::style::table_header::StyleSheet;
 
 pub struct TableHeader<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
 where
-	Renderer: 'a + renderer::Renderer,
-	Theme: StyleSheet,
+	Renderer: 'a + renderer::Renderer + text::Renderer<Font = iced::Font>,
+	Theme: Catalog,
 {
 	spacing: u16,
 	padding: Padding,
 	width: Length,
 	height: Length,
-	state: TableHeaderState,
 	leeway: u16,
 	on_resize: Option<(u16, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
 	children: Vec<Element<'a, Message, Theme, Renderer>>,
 	left_margin: bool,
 	right_margin: bool,
 	names: Vec<String>,
-	style: <Theme as StyleSheet>::Style,
+	sort: Option<(usize, SortDirection)>,
+	on_sort: Option<Box<dyn Fn(usize, SortDirection) -> Message + 'a>>,
+	on_column_move: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+	sortable: bool,
+	class: Theme::Class<'a>,
 }
 
 impl<'a, Message, Theme, Renderer> TableHeader<'a, Message, Theme, Renderer>
 where
-	Renderer: 'a + renderer::Renderer,
-	Theme: StyleSheet,
+	Renderer: 'a + renderer::Renderer + text::Renderer<Font = iced::Font>,
+	Theme: Catalog,
 	Message: 'a,
 {
 	/// Creates a new [`TableHeader`] displaying the provided content.
 	pub fn new(
-		state: TableHeaderState,
 		headers: Vec<(String, Element<'a, Message, Theme, Renderer>)>,
 		left_margin: Option<Length>,
 		right_margin: Option<Length>,
 	) -> Self
-	where
-		Renderer: 'a + renderer::Renderer,
-		Theme: StyleSheet,
 	{
 		let mut names = vec![];
 		let mut left = false;
@@ -135,22 +246,33 @@ where
 			width: Length::Fill,
 			height: Length::Shrink,
 			leeway: 0,
-			state,
 			on_resize: None,
 			children,
 			left_margin: left,
 			right_margin: right,
 			names,
-			style: Default::default(),
+			sort: None,
+			on_sort: None,
+			on_column_move: None,
+			sortable: false,
+			class: Theme::default(),
 		}
 	}
 
 	/// Sets the style of the [`TableHeader`].
-	pub fn style<S>(mut self, style: S) -> Self
+	#[must_use]
+	pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
 	where
-		S: Into<<Theme as StyleSheet>::Style>,
+		Theme::Class<'a>: From<StyleFn<'a, Theme, Style>>,
 	{
-		self.style = style.into();
+		self.class = (Box::new(style) as StyleFn<'a, Theme, Style>).into();
+		self
+	}
+
+	/// Sets the class of the [`TableHeader`].
+	#[must_use]
+	pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+		self.class = class.into();
 		self
 	}
 
@@ -188,6 +310,82 @@ where
 		self
 	}
 
+	/// Sets the column the data is currently sorted by and its [`SortDirection`].
+	///
+	/// A sort indicator is drawn in the header cell of that column.
+	pub fn sort(mut self, column: usize, direction: SortDirection) -> Self
+	where
+		Theme: 'a,
+	{
+		self.sort = Some((column, direction));
+		self.reserve_sort_indicator();
+		self
+	}
+
+	/// Sets the message that will be produced when a header cell is clicked.
+	///
+	/// The clicked column cycles through ascending, descending and unsorted.
+	pub fn on_sort<F>(mut self, f: F) -> Self
+	where
+		F: 'a + Fn(usize, SortDirection) -> Message,
+		Theme: 'a,
+	{
+		self.on_sort = Some(Box::new(f));
+		self.reserve_sort_indicator();
+		self
+	}
+
+	/// Pads the end of every header cell so the sort indicator gets its own
+	/// space instead of being drawn over the content of the cell.
+	fn reserve_sort_indicator(&mut self)
+	where
+		Theme: 'a,
+	{
+		if self.sortable {
+			return;
+		}
+		self.sortable = true;
+
+		let start = self.start_offset();
+		let end = start + self.column_count();
+		self.children = std::mem::take(&mut self.children)
+			.into_iter()
+			.enumerate()
+			.map(|(index, child)| {
+				if (start..end).contains(&index) {
+					Row::new()
+						.push(child)
+						.padding(Padding::ZERO.right(SORT_INDICATOR_WIDTH))
+						.into()
+				} else {
+					child
+				}
+			})
+			.collect();
+	}
+
+	/// Sets the message that will be produced when a column is dragged to a
+	/// new position, with its current and its new index.
+	///
+	/// A drop indicator is drawn between the header cells while dragging.
+	pub fn on_column_move<F>(mut self, f: F) -> Self
+	where
+		F: 'a + Fn(usize, usize) -> Message,
+	{
+		self.on_column_move = Some(Box::new(f));
+		self
+	}
+
+	/// Returns the offset of the first column in the children of the [`TableHeader`].
+	fn start_offset(&self) -> usize {
+		usize::from(self.left_margin)
+	}
+
+	/// Returns the number of columns of the [`TableHeader`].
+	fn column_count(&self) -> usize {
+		self.names.len()
+	}
+
 	/// Triggers the resize event
 	fn trigger_resize(
 		&self,
@@ -198,7 +396,6 @@ where
 		shell: &mut Shell<'_, Message>,
 	) {
 		if let Some((_, on_resize)) = &self.on_resize {
-			//TODO: Update
 			shell.publish(on_resize(ResizeEvent::ResizeColumn {
 				left_name,
 				left_width,
@@ -208,7 +405,154 @@ where
 		}
 	}
 
-	fn _trigger_finished(&self, shell: &mut Shell<'_, Message>) {
+	/// Returns the bounds of the header cells of the columns.
+	pub(crate) fn cells(&self, layout: Layout<'_>) -> Vec<Rectangle> {
+		layout
+			.children()
+			.skip(self.start_offset())
+			.take(self.column_count())
+			.map(|layout| layout.bounds())
+			.collect()
+	}
+
+	/// Publishes the message of a [`ColumnAction`].
+	pub(super) fn publish_action(&self, action: ColumnAction, shell: &mut Shell<'_, Message>) {
+		match action {
+			ColumnAction::Sort(column) => {
+				if let Some(on_sort) = &self.on_sort {
+					let direction = match self.sort {
+						Some((sorted, direction)) if sorted == column => direction.next(),
+						_ => SortDirection::Ascending,
+					};
+
+					shell.publish(on_sort(column, direction));
+				}
+			}
+			ColumnAction::Move(from, to) => {
+				if let Some(on_column_move) = &self.on_column_move {
+					shell.publish(on_column_move(from, to));
+				}
+			}
+		}
+	}
+
+	/// Returns whether the header cells react to presses.
+	pub(crate) fn has_column_actions(&self) -> bool {
+		self.on_sort.is_some() || self.on_column_move.is_some()
+	}
+
+	/// Draws the indicator showing where a dragged column is dropped, spanning
+	/// the height of `bounds`.
+	pub(crate) fn draw_drop_indicator(
+		&self,
+		state: &TableHeaderState,
+		renderer: &mut Renderer,
+		theme: &Theme,
+		cells: &[Rectangle],
+		bounds: Rectangle,
+	) {
+		let Some(x) = state.drop_indicator(cells) else {
+			return;
+		};
+
+		renderer.fill_quad(
+			renderer::Quad {
+				bounds: Rectangle {
+					x: x - DROP_INDICATOR_WIDTH / 2.0,
+					y: bounds.y,
+					width: DROP_INDICATOR_WIDTH,
+					height: bounds.height,
+				},
+				..renderer::Quad::default()
+			},
+			theme.style(&self.class, Status::Active).text_color,
+		);
+	}
+
+	/// Returns the width the content of the header cell of `column` takes up.
+	pub(crate) fn cell_width(&self, tree: &mut Tree, renderer: &Renderer, column: usize) -> Option<f32> {
+		let index = column + self.start_offset();
+		measure_width(self.children.get(index)?, tree.children.get_mut(index)?, renderer)
+	}
+
+	/// Draws the background of the [`TableHeader`] behind its first `count`
+	/// header cells and these cells.
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) fn draw_cells(
+		&self,
+		tree: &Tree,
+		renderer: &mut Renderer,
+		theme: &Theme,
+		style: &renderer::Style,
+		layout: Layout<'_>,
+		cursor: mouse::Cursor,
+		viewport: &Rectangle,
+		count: usize,
+	) {
+		let mut bounds = layout.bounds();
+		let cursor_position = cursor.position().unwrap_or_default();
+		let is_mouse_over = bounds.contains(cursor_position);
+		let count = count.saturating_add(self.start_offset());
+
+		if count < self.children.len() {
+			bounds.width = layout
+				.children()
+				.take(count)
+				.last()
+				.map_or(0.0, |cell| cell.bounds().x + cell.bounds().width - bounds.x);
+		}
+
+		let appearance = theme.style(
+			&self.class,
+			if is_mouse_over { Status::Hovered } else { Status::Active },
+		);
+
+		let background = renderer::Quad {
+			bounds: Rectangle {
+				x: bounds.x + appearance.offset_left as f32,
+				y: bounds.y,
+				width: bounds.width - appearance.offset_right as f32,
+				height: bounds.height,
+			},
+			border: Border {
+				width: appearance.border_width,
+				color: appearance.border_color,
+				radius: appearance.border_radius.into(),
+			},
+			shadow: Default::default(),
+		};
+
+		renderer.fill_quad(
+			background.into(),
+			appearance.background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+		);
+
+		for ((child, state), layout) in self
+			.children
+			.iter()
+			.zip(&tree.children)
+			.zip(layout.children())
+			.take(count)
+		{
+			child
+				.as_widget()
+				.draw(state, renderer, theme, style, layout, cursor, viewport)
+		}
+
+		if let Some((column, direction)) = self.sort {
+			if let Some(cell) = layout.children().take(count).nth(column + self.start_offset()) {
+				draw_sort_indicator(renderer, cell.bounds(), direction, appearance.text_color);
+			}
+		}
+	}
+
+	/// Lays out the header cells using the resolved widths of the table columns.
+	pub(crate) fn layout_columns(&self, tree: &mut Tree, renderer: &Renderer, widths: &[f32]) -> Node {
+		layout_cells(&self.children, &mut tree.children, renderer, widths, self.height)
+	}
+
+	/// Triggers the event of the end of a resize
+	fn trigger_finished(&self, shell: &mut Shell<'_, Message>) {
 		if let Some((_, on_resize)) = &self.on_resize {
 			shell.publish(on_resize(ResizeEvent::Finished));
 		}
@@ -218,9 +562,18 @@ where
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
 	for TableHeader<'a, Message, Theme, Renderer>
 where
-	Renderer: 'a + renderer::Renderer,
-	Theme: StyleSheet,
+	Renderer: 'a + renderer::Renderer + text::Renderer<Font = iced::Font>,
+	Theme: Catalog,
+	Message: 'a,
 {
+	fn tag(&self) -> tree::Tag {
+		tree::Tag::of::<TableHeaderState>()
+	}
+
+	fn state(&self) -> tree::State {
+		tree::State::new(TableHeaderState::default())
+	}
+
 	fn children(&self) -> Vec<Tree> {
 		self.children.iter().map(Tree::new).collect()
 	}
@@ -273,46 +626,9 @@ where
 		cursor: mouse::Cursor,
 		viewport: &Rectangle,
 	) {
-		let bounds = layout.bounds();
-		let cursor_position = cursor.position().unwrap_or_default();
-		let is_mouse_over = bounds.contains(cursor_position);
-
-		let appearance = if is_mouse_over {
-			theme.hovered(&self.style)
-		} else {
-			theme.appearance(&self.style)
-		};
-
-		let background = renderer::Quad {
-			bounds: Rectangle {
-				x: bounds.x + appearance.offset_left as f32,
-				y: bounds.y,
-				width: bounds.width - appearance.offset_right as f32,
-				height: bounds.height,
-			},
-			border: Border {
-				width: appearance.border_width,
-				color: appearance.border_color,
-				radius: appearance.border_radius.into(),
-			},
-			shadow: Default::default(),
-		};
-
-		renderer.fill_quad(
-			background.into(),
-			appearance.background.unwrap_or(Background::Color(Color::TRANSPARENT)),
-		);
-
-		for ((child, state), layout) in self
-			.children
-			.iter()
-			.zip(&tree.children)
-			.zip(layout.children())
-		{
-			child
-				.as_widget()
-				.draw(state, renderer, theme, style, layout, cursor, viewport)
-		}
+		self.draw_cells(tree, renderer, theme, style, layout, cursor, viewport, usize::MAX);
+		let state = tree.state.downcast_ref::<TableHeaderState>();
+		self.draw_drop_indicator(state, renderer, theme, &self.cells(layout), layout.bounds());
 	}
 
 	fn on_event(
@@ -326,10 +642,11 @@ where
 		shell: &mut Shell<'_, Message>,
 		viewport: &Rectangle,
 	) -> event::Status {
+		let state = tree.state.downcast_mut::<TableHeaderState>();
 		let cursor_position = cursor.position().unwrap_or_default();
 		let in_bounds = layout.bounds().contains(cursor_position);
 
-		if self.state.resizing || in_bounds {
+		if state.resize.resizing || in_bounds {
 			let child_len = self.children.len();
 			let start_offset = if self.left_margin { 1 } else { 0 };
 			let end_offset = if self.right_margin { 1 } else { 0 };
@@ -349,71 +666,35 @@ where
 				.collect::<Vec<_>>();
 
 			if self.on_resize.is_some() {
-				if !self.state.resizing {
-					self.state.resize_hovering = false;
-				}
-
-				for (idx, divider) in dividers.iter() {
-					if cursor_position.x > (divider - self.leeway as f32)
-						&& cursor_position.x < (divider + self.leeway as f32)
-					{
-						if !self.state.resize_hovering {
-							self.state.resizing_idx = *idx;
-						}
-
-						self.state.resize_hovering = true;
-					}
-				}
+				state.resize.hover_dividers(&dividers, cursor_position.x, f32::from(self.leeway));
 			}
 
 			match event {
 				Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-					if self.state.resize_hovering {
-						self.state.resizing = true;
-						self.state.starting_cursor_pos = Some(cursor_position);
-						self.state.starting_left_width = layout
-							.children()
-							.nth(self.state.resizing_idx)
-							.unwrap()
-							.bounds()
-							.width;
-						self.state.starting_right_width = layout
-							.children()
-							.nth(self.state.resizing_idx + 1)
-							.unwrap()
-							.bounds()
-							.width;
+					if state.resize.resize_hovering {
+						let width = |idx| layout.children().nth(idx).map_or(0.0, |layout| layout.bounds().width);
+						let (left_width, right_width) = (width(state.resize.resizing_idx), width(state.resize.resizing_idx + 1));
+						state.resize.start_resize(cursor_position, left_width, right_width);
 						return event::Status::Captured;
 					}
 				}
 				Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-					if self.state.resizing {
-						self.state.resizing = false;
-						let _ = self.state.starting_cursor_pos.take();
-						// TODO: UPDATE
-						//shell.publish(messages);
+					if state.resize.finish_resize() {
+						self.trigger_finished(shell);
 						return event::Status::Captured;
 					}
 				}
 				Event::Mouse(mouse::Event::CursorMoved { position }) => {
-					if self.state.resizing {
-						let delta = position.x - self.state.starting_cursor_pos.unwrap().x;
-
-						let left_width = self.state.starting_left_width;
-						let right_width = self.state.starting_right_width;
-
-						let max_width = left_width + right_width - 30.0;
-
-						let left_width = (left_width + delta).max(30.0).min(max_width) as u16;
-						let left_name = &self.names[self.state.resizing_idx - start_offset];
-						let right_width = (right_width - delta).max(30.0).min(max_width) as u16;
-						let right_name = &self.names[self.state.resizing_idx + 1 - start_offset];
+					if state.resize.resizing {
+						let (left_width, right_width) = state.resize.resized_widths(position.x, MIN_COLUMN_WIDTH);
+						let left_name = &self.names[state.resize.resizing_idx - start_offset];
+						let right_name = &self.names[state.resize.resizing_idx + 1 - start_offset];
 
 						self.trigger_resize(
 							left_name.clone(),
-							left_width,
+							left_width as u16,
 							right_name.clone(),
-							right_width,
+							right_width as u16,
 							shell,
 						);
 						return event::Status::Captured;
@@ -422,10 +703,10 @@ where
 				_ => {}
 			}
 		} else {
-			self.state.resize_hovering = false;
+			state.resize.resize_hovering = false;
 		}
 
-		self.children
+		let status = self.children
 			.iter_mut()
 			.zip(&mut tree.children)
 			.zip(layout.children())
@@ -441,23 +722,38 @@ where
 					viewport,
 				)
 			})
-			.fold(event::Status::Ignored, event::Status::merge)
+			.fold(event::Status::Ignored, event::Status::merge);
+
+		if status == event::Status::Ignored && self.has_column_actions() {
+			let (status, action) = state.update_columns(&event, cursor, &self.cells(layout));
+
+			if let Some(action) = action {
+				self.publish_action(action, shell);
+			}
+
+			return status;
+		}
+
+		status
 	}
 
 
 	fn mouse_interaction(
 		&self,
-		_tree: &Tree,
+		tree: &Tree,
 		layout: Layout<'_>,
 		cursor: mouse::Cursor,
 		_viewport: &Rectangle,
 		_renderer: &Renderer,
 	) -> mouse::Interaction {
+		let state = tree.state.downcast_ref::<TableHeaderState>();
 		let bounds = layout.bounds();
 		let cursor_position = cursor.position().unwrap_or_default();
 		let is_mouse_over = bounds.contains(cursor_position);
 
-		if is_mouse_over {
+		if state.dragging {
+			mouse::Interaction::Grabbing
+		} else if is_mouse_over {
 			mouse::Interaction::Pointer
 		} else {
 			mouse::Interaction::default()
@@ -470,21 +766,168 @@ impl<'a, Message, Theme, Renderer> From<TableHeader<'a, Message, Theme, Renderer
 	for Element<'a, Message, Theme, Renderer>
 where
 	Message: 'a,
-	Renderer: 'a + renderer::Renderer,
-	Theme: 'a + StyleSheet
+	Renderer: 'a + renderer::Renderer + text::Renderer<Font = iced::Font>,
+	Theme: 'a + Catalog
 {
 	fn from(header: TableHeader<'a, Message, Theme, Renderer>) -> Self {
 		Self::new(header)
 	}
 }
 
-#[derive(Debug, Clone)]
-pub enum ResizeEvent {
-	ResizeColumn {
-		left_name: String,
-		left_width: u16,
-		right_name: String,
-		right_width: u16,
-	},
-	Finished,
+/// The direction a [`TableHeader`] column is sorted in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+	/// The column is sorted in ascending order.
+	Ascending,
+	/// The column is sorted in descending order.
+	Descending,
+	/// The column is not sorted.
+	#[default]
+	Unsorted,
+}
+
+impl SortDirection {
+	/// Returns the direction following this one when a header cell is clicked.
+	#[must_use]
+	pub const fn next(self) -> Self {
+		match self {
+			Self::Unsorted => Self::Ascending,
+			Self::Ascending => Self::Descending,
+			Self::Descending => Self::Unsorted,
+		}
+	}
+}
+
+/// Draws the caret indicating the [`SortDirection`] at the right of a header cell.
+fn draw_sort_indicator<Renderer>(
+	renderer: &mut Renderer,
+	bounds: Rectangle,
+	direction: SortDirection,
+	color: Color,
+) where
+	Renderer: text::Renderer<Font = iced::Font>,
+{
+	let icon = match direction {
+		SortDirection::Ascending => RequiredIcons::CaretUpFill,
+		SortDirection::Descending => RequiredIcons::CaretDownFill,
+		SortDirection::Unsorted => return,
+	};
+
+	let size = renderer.default_size();
+	let bounds = Rectangle {
+		x: bounds.x + bounds.width - SORT_INDICATOR_WIDTH,
+		width: SORT_INDICATOR_WIDTH,
+		..bounds
+	};
+
+	renderer.fill_text(
+		Text {
+			content: char::from(icon).to_string(),
+			bounds: bounds.size(),
+			size: Pixels(size.0 * 0.75),
+			font: REQUIRED_FONT,
+			horizontal_alignment: Horizontal::Center,
+			vertical_alignment: Vertical::Center,
+			line_height: LineHeight::default(),
+			shaping: Shaping::Basic,
+			wrapping: Wrapping::default(),
+		},
+		bounds.center(),
+		color,
+		bounds,
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{drop_index, moved_index, SortDirection, TableHeader, SORT_INDICATOR_WIDTH};
+	use iced::{
+		advanced::{clipboard, layout::Limits, widget::Tree, Layout, Shell, Widget},
+		mouse,
+		widget::Space,
+		Element, Event, Point, Rectangle, Size,
+	};
+
+	#[test]
+	fn sort_direction_cycle_test() {
+		let direction = SortDirection::default();
+		assert_eq!(direction, SortDirection::Unsorted);
+		assert_eq!(direction.next(), SortDirection::Ascending);
+		assert_eq!(direction.next().next(), SortDirection::Descending);
+		assert_eq!(direction.next().next().next(), SortDirection::Unsorted);
+	}
+
+	#[test]
+	fn drop_index_test() {
+		let cells = [
+			Rectangle { x: 0.0, y: 0.0, width: 100.0, height: 20.0 },
+			Rectangle { x: 100.0, y: 0.0, width: 50.0, height: 20.0 },
+			Rectangle { x: 150.0, y: 0.0, width: 100.0, height: 20.0 },
+		];
+
+		assert_eq!(drop_index(&cells, 10.0), 0);
+		assert_eq!(drop_index(&cells, 60.0), 1);
+		assert_eq!(drop_index(&cells, 130.0), 2);
+		assert_eq!(drop_index(&cells, 300.0), 3);
+
+		assert_eq!(moved_index(0, 3), 2);
+		assert_eq!(moved_index(2, 0), 0);
+		assert_eq!(moved_index(1, 1), 1);
+		assert_eq!(moved_index(1, 2), 1);
+	}
+
+	#[test]
+	fn sort_indicator_space_test() {
+		let headers = |width| -> Vec<(String, Element<'_, (), iced::Theme, ()>)> {
+			vec![(String::from("Name"), Space::with_width(width).into())]
+		};
+
+		let header = TableHeader::new(headers(50.0), None, None);
+		let mut tree = Tree::new(&header as &dyn iced::advanced::Widget<_, _, _>);
+		assert_eq!(header.cell_width(&mut tree, &(), 0), Some(50.0));
+
+		let header = TableHeader::new(headers(50.0), None, None)
+			.sort(0, SortDirection::Ascending)
+			.on_sort(|_, _| ());
+		let mut tree = Tree::new(&header as &dyn iced::advanced::Widget<_, _, _>);
+		assert_eq!(header.cell_width(&mut tree, &(), 0), Some(50.0 + SORT_INDICATOR_WIDTH));
+	}
+
+	#[test]
+	fn column_drag_test() {
+		let mut tree = None;
+		let mut messages = Vec::new();
+
+		// The header is rebuilt for every event, like in a `view`.
+		for (event, position) in [
+			(mouse::Event::ButtonPressed(mouse::Button::Left), Point::new(50.0, 10.0)),
+			(mouse::Event::CursorMoved { position: Point::new(240.0, 10.0) }, Point::new(240.0, 10.0)),
+			(mouse::Event::ButtonReleased(mouse::Button::Left), Point::new(240.0, 10.0)),
+		] {
+			let headers = ["A", "B", "C"]
+				.into_iter()
+				.map(|name| -> (String, Element<'_, (usize, usize), iced::Theme, ()>) {
+					(String::from(name), Space::new(100.0, 20.0).into())
+				})
+				.collect();
+			let mut header = TableHeader::new(headers, None, None).on_column_move(|from, to| (from, to));
+
+			let tree = tree.get_or_insert_with(|| Tree::new(&header as &dyn Widget<_, _, _>));
+			tree.diff(&header as &dyn Widget<_, _, _>);
+			let node = header.layout(tree, &(), &Limits::new(Size::ZERO, Size::new(300.0, 20.0)));
+
+			let _ = header.on_event(
+				tree,
+				Event::Mouse(event),
+				Layout::new(&node),
+				mouse::Cursor::Available(position),
+				&(),
+				&mut clipboard::Null,
+				&mut Shell::new(&mut messages),
+				&Rectangle::with_size(Size::new(300.0, 20.0)),
+			);
+		}
+
+		assert_eq!(messages, vec![(0, 1)]);
+	}
 }
@@ -2,18 +2,25 @@
 //!
 //! *This API requires the following crate features to be activated: `table`*
 
+use std::rc::Rc;
+
 use iced::{
     advanced::{
         layout::{flex, Limits, Node},
-        renderer,
-        widget::Tree,
+        overlay, renderer,
+        widget::{Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
     event, mouse, Alignment, Background, Border, Color, Element, Event, Length, Padding, Rectangle,
-    Size,
+    Size, Vector,
 };
 
-pub use crate::style::table_row::StyleSheet;
+use super::layout::{layout_cells, measure_width};
+
+pub use crate::style::{
+    status::Status,
+    table_row::{Catalog, RowStyleFn, Style},
+};
 
 /// A selectable table row
 ///
@@ -45,10 +52,10 @@ pub use crate::style::table_row::StyleSheet;
 /// ```
 #[allow(missing_debug_implementations)]
 
-pub struct TableRow<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+pub struct TableRow<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer, K = usize>
 where
     Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer,
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     padding: Padding,
     spacing: u16,
@@ -56,22 +63,26 @@ where
     height: Length,
     max_width: u32,
     max_height: u32,
-    row_id: u16,
+    row_id: K,
+    row_index: usize,
     inner_row_height: u32,
     horizontal_alignment: Alignment,
     vertical_alignment: Alignment,
-    style: <Theme as StyleSheet>::Style,
+    class: Rc<Theme::Class<'a>>,
+    selected: bool,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
     on_press: Option<Box<dyn Fn(Event) -> Message + 'a>>,
 }
 
-impl<'a, Message, Theme, Renderer> TableRow<'a, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer, K> TableRow<'a, Message, Theme, Renderer, K>
 where
     Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer,
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
-    /// Creates an new [`TableRow`] with the given content.
-    pub fn new<T>(row_content: Vec<T>, row_id: u16) -> Self
+    /// Creates an new [`TableRow`] with the given content, identified by `row_id`.
+    ///
+    /// The id can be any key, like the database id of the displayed entry.
+    pub fn new<T>(row_content: Vec<T>, row_id: K) -> Self
     where
         T: Into<Element<'a, Message, Theme, Renderer>>,
     {
@@ -89,11 +100,13 @@ where
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
-            row_id: row_id,
+            row_id,
+            row_index: 0,
             inner_row_height: u32::MAX,
             horizontal_alignment: Alignment::Start,
             vertical_alignment: Alignment::Start,
-            style: Default::default(),
+            class: Rc::new(Theme::default()),
+            selected: false,
             children: children,
             on_press: None,
         }
@@ -105,11 +118,42 @@ where
     }
 
     /// Sets the style of the [`TableRow`].
-    pub fn style<S>(mut self, style: S) -> Self
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status, usize) -> Style + 'a) -> Self
     where
-        S: Into<<Theme as StyleSheet>::Style>,
+        Theme::Class<'a>: From<RowStyleFn<'a, Theme>>,
     {
-        self.style = style.into();
+        self.class = Rc::new((Box::new(style) as RowStyleFn<'a, Theme>).into());
+        self
+    }
+
+    /// Sets the class of the [`TableRow`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = Rc::new(class.into());
+        self
+    }
+
+    /// Shares the class of the table with the [`TableRow`].
+    pub(crate) fn set_class(&mut self, class: Rc<Theme::Class<'a>>) {
+        self.class = class;
+    }
+
+    /// Sets the position of the [`TableRow`] in its table, which is passed to
+    /// the style of the row.
+    pub fn row_index(mut self, row_index: usize) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Returns the id of the [`TableRow`].
+    pub fn row_id(&self) -> &K {
+        &self.row_id
+    }
+
+    /// Sets whether the [`TableRow`] is drawn with the selected appearance.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
         self
     }
 
@@ -174,42 +218,21 @@ where
         self.padding = p;
         self
     }
-}
 
-impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for TableRow<'a, Message, Theme, Renderer>
-where
-    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer,
-    Theme: StyleSheet,
-{
-    fn children(&self) -> Vec<Tree> {
-        self.children.iter().map(Tree::new).collect()
+    /// Lays out the cells of the [`TableRow`] using the resolved widths of the table columns.
+    pub(crate) fn layout_columns(&self, tree: &mut Tree, renderer: &Renderer, widths: &[f32]) -> Node {
+        layout_cells(&self.children, &mut tree.children, renderer, widths, self.height)
     }
 
-    fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: self.height,
-        }
-    }
-
-    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        let limits = limits.loose().width(self.width).height(self.height);
-        flex::resolve(
-            flex::Axis::Horizontal,
-            renderer,
-            &limits,
-            self.width,
-            self.height,
-            self.padding,
-            self.spacing as f32,
-            Alignment::Start,
-            &self.children,
-            &mut tree.children,
-        )
+    /// Returns the width the content of the cell at `index` takes up.
+    pub(crate) fn cell_width(&self, tree: &mut Tree, renderer: &Renderer, index: usize) -> Option<f32> {
+        measure_width(self.children.get(index)?, tree.children.get_mut(index)?, renderer)
     }
 
-    fn draw(
+    /// Draws the background of the [`TableRow`] behind its first `count`
+    /// cells and these cells.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw_cells(
         &self,
         tree: &Tree,
         renderer: &mut Renderer,
@@ -218,9 +241,10 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         viewport: &Rectangle,
+        count: usize,
     ) {
         let cursor_position = cursor.position().unwrap_or_default();
-        let bounds = layout.bounds();
+        let mut bounds = layout.bounds();
         let mut custom_bounds = layout.bounds();
 
         // inner_row_height set?
@@ -230,11 +254,32 @@ where
 
         let is_mouse_over = custom_bounds.contains(cursor_position);
 
-        let appearance = if is_mouse_over {
-            theme.hovered(&self.style, self.row_id)
+        let status = if self.selected {
+            Status::Selected
+        } else if is_mouse_over {
+            Status::Hovered
         } else {
-            theme.appearance(&self.style, self.row_id)
+            Status::Active
         };
+        let appearance = theme.style(&self.class, status, self.row_index);
+
+        if count < self.children.len() {
+            bounds.width = layout
+                .children()
+                .take(count)
+                .last()
+                .map_or(0.0, |cell| cell.bounds().x + cell.bounds().width - bounds.x);
+
+            if let Some(background) = appearance.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+        }
 
         let background = renderer::Quad {
             bounds: Rectangle {
@@ -264,6 +309,7 @@ where
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
+            .take(count)
         {
             // Draw cell background, if required
             let cell_background = renderer::Quad {
@@ -293,6 +339,66 @@ where
                 .draw(state, renderer, theme, style, layout, cursor, viewport)
         }
     }
+}
+
+impl<'a, Message, Theme, Renderer, K> Widget<Message, Theme, Renderer>
+    for TableRow<'a, Message, Theme, Renderer, K>
+where
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer,
+    Theme: Catalog,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.loose().width(self.width).height(self.height);
+        flex::resolve(
+            flex::Axis::Horizontal,
+            renderer,
+            &limits,
+            self.width,
+            self.height,
+            self.padding,
+            self.spacing as f32,
+            Alignment::Start,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.draw_cells(
+            tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+            self.children.len(),
+        );
+    }
 
     fn mouse_interaction(
         &self,
@@ -332,28 +438,40 @@ where
 
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
         let cursor_position = cursor.position().unwrap_or_default();
-        let _in_bounds = layout.bounds().contains(cursor_position);
 
-        /*let status_from_content = self.container.unwrap().on_event(
-            tree,
-            event.clone(),
-            layout.children().next().unwrap(),
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            viewport,
-        );*/
+        let status_from_content = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status_from_content == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if let Some(on_press) = &self.on_press {
@@ -367,39 +485,55 @@ where
 
                     if bounds.contains(cursor_position) {
                         shell.publish(on_press(event));
+                        return event::Status::Captured;
                     }
                 }
-                event::Status::Captured
+                event::Status::Ignored
             }
             _ => event::Status::Ignored,
         }
     }
 
-    /*fn overlay<'b>(
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn overlay<'b>(
         &'b mut self,
         tree: &'b mut Tree,
         layout: Layout<'_>,
         renderer: &Renderer,
-        cursor: Vector, // Change the type of the `cursor` parameter
+        translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        /*tree(
-            tree,
-            layout.children().next().unwrap(),
-            renderer,
-            cursor,
-        )*/
-        None
-    }*/
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
 }
 
-impl<'a, Message, Theme, Renderer> From<TableRow<'a, Message, Theme, Renderer>>
+impl<'a, Message, Theme, Renderer, K> From<TableRow<'a, Message, Theme, Renderer, K>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
     Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
+    K: 'a,
 {
-    fn from(row: TableRow<'a, Message, Theme, Renderer>) -> Self {
+    fn from(row: TableRow<'a, Message, Theme, Renderer, K>) -> Self {
         Self::new(row)
     }
 }
@@ -0,0 +1,2282 @@
+//! Displays a [`Table`] composed of a [`TableHeader`] and a [`TableRow`] per entry.
+//!
+//! *This API requires the following crate features to be activated: `table`*
+
+use iced::{
+    advanced::{
+        clipboard,
+        layout::{Limits, Node},
+        mouse,
+        overlay::Group,
+        renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    keyboard::{self, key::Named},
+    overlay,
+    widget::{text, Row, Space, Text},
+    window, Alignment, Color, Element, Event, Length, Rectangle, Size, Vector,
+};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    ops::Range,
+    rc::Rc,
+};
+
+use super::{
+    column::TableColumn,
+    edit::{focus_first, CellEdit},
+    export::{self, CopyMode, ExportFormat},
+    filter::FilterValue,
+    group::{self, flatten, group_indices, indent, Line, DISCLOSURE_WIDTH},
+    layout::{resolve_widths, MIN_COLUMN_WIDTH},
+    selection,
+    table_header::{self, SortDirection, TableHeader, TableHeaderState},
+    table_row::{self, RowStyleFn, Status, TableRow},
+};
+use crate::style::status::StyleFn;
+
+/// The default leeway, in pixels, around a column divider in which the
+/// column can be resized.
+const DEFAULT_LEEWAY: u16 = 5;
+
+/// The height assumed to be visible by a virtualized [`Table`] before it
+/// received its first viewport.
+const DEFAULT_VIRTUAL_HEIGHT: f32 = 1080.0;
+
+/// Produces the message of an edit of a cell of a [`Table`].
+type OnCellEdit<'a, K, Message> = Box<dyn Fn(K, usize, CellEdit) -> Message + 'a>;
+
+/// The built rows of a [`Table`] with the positions of their lines.
+type Rows<'a, Message, Theme, Renderer, K> =
+    Vec<(usize, TableRow<'a, Message, Theme, Renderer, Option<K>>)>;
+
+/// A table displaying a slice of data using a list of [`TableColumn`]s.
+///
+/// The table keeps the widths of its header and rows in sync, columns can be
+/// resized by dragging the dividers between the header cells. Double-clicking
+/// a divider fits the column left of it to its widest cell. The rows can be
+/// grouped into collapsible groups with [`group_by`](Self::group_by), and
+/// hierarchical data is displayed as a tree table with [`tree`](Self::tree).
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::text;
+/// # use iced_aw::table::{Table, TableColumn};
+/// #
+/// struct Person {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {}
+///
+/// let people = vec![Person { name: String::from("Alice"), age: 42 }];
+///
+/// let table: Table<'_, Person, Message> = Table::new(
+///     vec![
+///         TableColumn::new("Name", |person: &Person| text(&person.name)),
+///         TableColumn::new("Age", |person: &Person| text(person.age)).width(60.0),
+///     ],
+///     &people,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer, K = usize>
+where
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: table_header::Catalog + table_row::Catalog,
+{
+    /// The columns of the table.
+    columns: Vec<TableColumn<'a, T, Message, Theme, Renderer>>,
+    /// The displayed entries of the data, in data order for flat tables and
+    /// in depth-first order for tree tables.
+    entries: Vec<&'a T>,
+    /// The keys of the entries.
+    keys: Vec<K>,
+    /// Whether the rows are the nodes of trees.
+    tree: bool,
+    /// The header displaying the column titles.
+    header: TableHeader<'a, Message, Theme, Renderer>,
+    /// The row of the filters of the columns, shown below the header.
+    filters: Option<TableRow<'a, Message, Theme, Renderer>>,
+    /// The rows of the lines in the window of a virtualized table, or of all
+    /// shown lines, built when the table is diffed or laid out. Group header
+    /// rows have no key.
+    rows: RefCell<Rows<'a, Message, Theme, Renderer, K>>,
+    /// What the rows display, in display order.
+    lines: Vec<Line>,
+    /// The labels of the groups of rows.
+    groups: Vec<String>,
+    /// The indices of the entries of the groups.
+    group_entries: Vec<Vec<usize>>,
+    /// The labels of the collapsed groups.
+    collapsed: HashSet<String>,
+    /// The message produced when a group header row is clicked.
+    on_group_toggle: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// The height of the rows.
+    row_height: Option<Length>,
+    /// The style of the rows.
+    row_class: Option<Rc<<Theme as table_row::Catalog>::Class<'a>>>,
+    /// The width of the table.
+    width: Length,
+    /// The height of the table.
+    height: Length,
+    /// The leeway around a column divider for resizing.
+    leeway: u16,
+    /// The estimated row height if only the visible rows are laid out.
+    virtualization: Option<f32>,
+    /// The number of leading columns kept visible when scrolling horizontally.
+    frozen_columns: usize,
+    /// Whether the header is kept visible when scrolling vertically.
+    sticky_header: bool,
+    /// The keys of the selected rows.
+    selection: Vec<K>,
+    /// The message produced when the selection changes.
+    on_selection_change: Option<Box<dyn Fn(Vec<K>) -> Message + 'a>>,
+    /// What is copied to the clipboard.
+    copy_mode: CopyMode,
+    /// The key of the row and the column of the edited cell.
+    editing: Option<(K, usize)>,
+    /// The message produced when a cell is edited.
+    on_cell_edit: Option<OnCellEdit<'a, K, Message>>,
+    /// The message produced when a node of a tree table is expanded.
+    on_expand: Option<Box<dyn Fn(K) -> Message + 'a>>,
+    /// The message produced when a node of a tree table is collapsed.
+    on_collapse: Option<Box<dyn Fn(K) -> Message + 'a>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Table<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a + table_header::Catalog + table_row::Catalog + text::Catalog,
+{
+    /// Creates a new [`Table`] with the given columns, displaying a row for
+    /// every entry of `data`.
+    ///
+    /// The rows are identified by their position in `data`, use
+    /// [`keyed`](Self::keyed) to identify them by a key of their entries.
+    pub fn new(columns: Vec<TableColumn<'a, T, Message, Theme, Renderer>>, data: &'a [T]) -> Self {
+        Self::with_keys(columns, data, 0..data.len())
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer, K> Table<'a, T, Message, Theme, Renderer, K>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a + table_header::Catalog + table_row::Catalog + text::Catalog,
+    K: 'a + Clone + Eq + Hash,
+{
+    /// Creates a new [`Table`] with the given columns, displaying a row for
+    /// every entry of `data` identified by the key returned by `key`.
+    ///
+    /// The selection refers to these keys, so it stays with its entries
+    /// when the data is sorted. The table keeps the keys of the anchor and
+    /// the cursor of the selection in its state, which needs `'static` keys.
+    pub fn keyed<F>(
+        columns: Vec<TableColumn<'a, T, Message, Theme, Renderer>>,
+        data: &'a [T],
+        key: F,
+    ) -> Self
+    where
+        F: Fn(&T) -> K,
+    {
+        Self::with_keys(columns, data, data.iter().map(key))
+    }
+
+    /// Creates a new tree [`Table`] with the given columns, displaying a row
+    /// for every node of the trees of `roots` identified by the key returned
+    /// by `key`.
+    ///
+    /// `children` returns the children of a node, or `None` if it is a leaf.
+    /// The children of the nodes with a key in `expanded` are shown below
+    /// them, indented in the first column. Clicking the disclosure caret of a
+    /// node produces the messages set with [`on_expand`](Self::on_expand)
+    /// and [`on_collapse`](Self::on_collapse), so children can be loaded
+    /// lazily, returning an empty slice until then.
+    pub fn tree<F, C>(
+        columns: Vec<TableColumn<'a, T, Message, Theme, Renderer>>,
+        roots: &'a [T],
+        key: F,
+        children: C,
+        expanded: impl IntoIterator<Item = K>,
+    ) -> Self
+    where
+        F: Fn(&T) -> K,
+        C: Fn(&'a T) -> Option<&'a [T]>,
+    {
+        let expanded = expanded.into_iter().collect::<HashSet<_>>();
+        let nodes = flatten(roots, children, |node| expanded.contains(&key(node)));
+
+        Self::with_entries(
+            columns,
+            nodes
+                .into_iter()
+                .map(|(node, depth, expanded)| (node, key(node), depth, expanded)),
+            true,
+        )
+    }
+
+    /// Creates a new [`Table`] with a row for every entry of `data` and key
+    /// of `keys`.
+    fn with_keys(
+        columns: Vec<TableColumn<'a, T, Message, Theme, Renderer>>,
+        data: &'a [T],
+        keys: impl IntoIterator<Item = K>,
+    ) -> Self {
+        Self::with_entries(
+            columns,
+            data.iter()
+                .zip(keys)
+                .map(|(entry, key)| (entry, key, 0, None)),
+            false,
+        )
+    }
+
+    /// Creates a new [`Table`] with a row for every entry with its key, the
+    /// depth of its node and whether the node is expanded.
+    fn with_entries(
+        columns: Vec<TableColumn<'a, T, Message, Theme, Renderer>>,
+        entries: impl IntoIterator<Item = (&'a T, K, usize, Option<bool>)>,
+        tree: bool,
+    ) -> Self {
+        let headers = columns
+            .iter()
+            .map(|column| (column.title.clone(), Text::new(column.title.clone()).into()))
+            .collect();
+
+        let mut keys = Vec::new();
+        let mut lines = Vec::new();
+
+        let entries = entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, (entry, key, depth, expanded))| {
+                keys.push(key);
+                lines.push(Line::Entry {
+                    index,
+                    group: None,
+                    depth,
+                    expanded,
+                });
+                entry
+            })
+            .collect();
+
+        Self {
+            columns,
+            entries,
+            keys,
+            tree,
+            header: TableHeader::new(headers, None, None),
+            filters: None,
+            rows: RefCell::new(Vec::new()),
+            lines,
+            groups: Vec::new(),
+            group_entries: Vec::new(),
+            collapsed: HashSet::new(),
+            on_group_toggle: None,
+            row_height: None,
+            row_class: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            leeway: DEFAULT_LEEWAY,
+            virtualization: None,
+            frozen_columns: 0,
+            sticky_header: false,
+            selection: Vec::new(),
+            on_selection_change: None,
+            copy_mode: CopyMode::default(),
+            editing: None,
+            on_cell_edit: None,
+            on_expand: None,
+            on_collapse: None,
+        }
+    }
+
+    /// Groups the rows of the [`Table`] by the label returned by `key`, in
+    /// the order in which the groups first appear in the data.
+    ///
+    /// Every group is preceded by a header row showing its label, the number
+    /// of its rows and the [`aggregate`](TableColumn::aggregate)s of the
+    /// columns. Clicking a group header row produces the message set with
+    /// [`on_group_toggle`](Self::on_group_toggle).
+    ///
+    /// Every shown node of a tree [`Table`] is grouped on its own, so the
+    /// rows are best grouped by a label shared by all nodes of a tree.
+    #[must_use]
+    pub fn group_by<F>(mut self, key: F) -> Self
+    where
+        F: Fn(&T) -> String,
+    {
+        let mut entries = (0..self.entries.len()).map(|_| None).collect::<Vec<_>>();
+
+        for line in std::mem::take(&mut self.lines) {
+            if let Line::Entry { index, .. } = line {
+                entries[index] = Some(line);
+            }
+        }
+
+        let groups = group_indices(self.entries.iter().map(|entry| key(entry)));
+        self.groups = Vec::with_capacity(groups.len());
+        self.group_entries = Vec::with_capacity(groups.len());
+
+        for (group, (label, indices)) in groups.into_iter().enumerate() {
+            self.lines.push(Line::Group(group));
+
+            for &index in &indices {
+                if let Some(Line::Entry {
+                    depth, expanded, ..
+                }) = entries[index].take()
+                {
+                    self.lines.push(Line::Entry {
+                        index,
+                        group: Some(group),
+                        depth,
+                        expanded,
+                    });
+                }
+            }
+
+            self.groups.push(label);
+            self.group_entries.push(indices);
+        }
+
+        self
+    }
+
+    /// Returns the row of the line at `line`, with the editor of the edited
+    /// cell and the properties set on the [`Table`].
+    fn row(
+        &self,
+        line: usize,
+        selected: &HashSet<&K>,
+    ) -> TableRow<'a, Message, Theme, Renderer, Option<K>> {
+        let mut row = match self.lines[line] {
+            Line::Entry { index, depth, .. } => {
+                let entry = self.entries[index];
+                let edited = self
+                    .editing
+                    .as_ref()
+                    .filter(|(key, _)| self.keys.get(index) == Some(key))
+                    .map(|(_, column)| *column);
+                let cells = self
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, column)| {
+                        let cell = match &column.editor {
+                            Some(editor) if edited == Some(idx) => editor(entry),
+                            _ => (column.view)(entry),
+                        };
+                        if self.tree && idx == 0 {
+                            indent(cell, depth)
+                        } else {
+                            cell
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let key = &self.keys[index];
+
+                TableRow::new(cells, Some(key.clone())).selected(selected.contains(key))
+            }
+            Line::Group(group) => TableRow::new(self.group_cells(group), None),
+        }
+        .row_index(line);
+
+        if let Some(height) = self.row_height {
+            row = row.height(height);
+        }
+        if let Some(class) = &self.row_class {
+            row.set_class(Rc::clone(class));
+        }
+        row
+    }
+
+    /// Returns the cells of the header row of the group at `group`, showing
+    /// its label, the number of its rows and the aggregates of the columns.
+    fn group_cells(&self, group: usize) -> Vec<Element<'a, Message, Theme, Renderer>> {
+        let indices = &self.group_entries[group];
+        let grouped = indices
+            .iter()
+            .map(|&index| self.entries[index])
+            .collect::<Vec<_>>();
+
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                let aggregate = column
+                    .aggregate
+                    .as_ref()
+                    .map(|aggregate| aggregate(&grouped));
+
+                if idx == 0 {
+                    Row::new()
+                        .push(Space::with_width(DISCLOSURE_WIDTH))
+                        .push(Text::new(format!(
+                            "{} ({})",
+                            self.groups[group],
+                            indices.len()
+                        )))
+                        .push_maybe(aggregate)
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                        .into()
+                } else {
+                    aggregate.unwrap_or_else(|| Space::new(Length::Shrink, Length::Shrink).into())
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the lines of the rows in the window of a virtualized
+    /// [`Table`], the visible part with a screen above and below it, or all
+    /// shown lines.
+    fn window(&self, state: &TableState<K>) -> Vec<usize> {
+        let lines = (0..self.lines.len()).filter(|&line| !self.is_hidden(line));
+        let Some(estimated_height) = self.virtualization else {
+            return lines.collect();
+        };
+
+        // Build an additional screen above and below the visible part, so
+        // that scrolling doesn't require a new layout on every frame.
+        let (top, height) = state.visible.unwrap_or((0.0, DEFAULT_VIRTUAL_HEIGHT));
+        let window = (top - height)..(top + 2.0 * height);
+        let mut y = 0.0;
+
+        lines
+            .filter(|&line| {
+                let start = y;
+                y += state
+                    .row_heights
+                    .get(line)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(estimated_height);
+                y >= window.start && start <= window.end
+            })
+            .collect()
+    }
+
+    /// Builds the rows of the lines in the window of the [`Table`] and moves
+    /// the trees of the rows staying in the window to their new positions
+    /// in `children`, returning whether the rows changed.
+    ///
+    /// The trees of new rows are left empty until `children` is diffed.
+    fn build_rows(&self, state: &mut TableState<K>, children: &mut Vec<Tree>) -> bool {
+        let window = self.window(state);
+        let mut rows = self.rows.borrow_mut();
+        let is_built = rows
+            .iter()
+            .map(|(line, _)| *line)
+            .eq(window.iter().copied());
+
+        if is_built && state.built == window {
+            return false;
+        }
+
+        if !is_built {
+            let selected = self.selection.iter().collect::<HashSet<_>>();
+            *rows = window
+                .iter()
+                .map(|&line| (line, self.row(line, &selected)))
+                .collect();
+        }
+
+        // The children are the header, the built rows and the filter row.
+        let mut trees = std::mem::take(children).into_iter();
+        let header = trees.next().unwrap_or_else(Tree::empty);
+        let built = std::mem::take(&mut state.built)
+            .into_iter()
+            .zip(trees.by_ref())
+            .collect::<Vec<_>>();
+        let mut built = built.into_iter().peekable();
+
+        children.push(header);
+        for line in &window {
+            while built.next_if(|(built, _)| built < line).is_some() {}
+            children.push(
+                built
+                    .next_if(|(built, _)| built == line)
+                    .map_or_else(Tree::empty, |(_, tree)| tree),
+            );
+        }
+        children.extend(trees);
+
+        state.built = window;
+        true
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer, K> Table<'a, T, Message, Theme, Renderer, K>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a + table_header::Catalog + table_row::Catalog,
+    K: 'a + Clone + Eq + Hash,
+{
+    /// Sets the width of the [`Table`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the header of the [`Table`].
+    #[must_use]
+    pub fn header_height(mut self, height: impl Into<Length>) -> Self {
+        self.header = self.header.height(height.into());
+        self
+    }
+
+    /// Sets the height of every row of the [`Table`].
+    #[must_use]
+    pub fn row_height(mut self, height: impl Into<Length>) -> Self {
+        let height = height.into();
+        self.row_height = Some(height);
+        self.filters = self.filters.map(|filters| filters.height(height));
+        self
+    }
+
+    /// Sets the column the data is currently sorted by and its [`SortDirection`].
+    #[must_use]
+    pub fn sort(mut self, column: usize, direction: SortDirection) -> Self {
+        self.header = self.header.sort(column, direction);
+        self
+    }
+
+    /// Sets the message that will be produced when a header cell is clicked
+    /// to change the sorting of the [`Table`].
+    #[must_use]
+    pub fn on_sort<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize, SortDirection) -> Message,
+    {
+        self.header = self.header.on_sort(f);
+        self
+    }
+
+    /// Sets the message that will be produced when a column is dragged by
+    /// its header cell to a new position, with its current and its new index.
+    ///
+    /// The application is expected to reorder the columns it passes to the
+    /// [`Table`], a drop indicator is drawn while dragging.
+    #[must_use]
+    pub fn on_column_move<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Message,
+    {
+        self.header = self.header.on_column_move(f);
+        self
+    }
+
+    /// Sets the leeway, in pixels, around the column dividers in which the
+    /// columns can be resized. A leeway of 0 disables resizing.
+    #[must_use]
+    pub fn resize_leeway(mut self, leeway: u16) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Only builds, lays out and draws the rows near the viewport, so that
+    /// tables with a huge amount of rows can be scrolled smoothly.
+    ///
+    /// The rows of the visible part and of a screen above and below it are
+    /// built from the columns when the table is laid out, the other rows
+    /// are assumed to be of their last measured or the given estimated
+    /// height. Setting a fixed [`row_height`](Self::row_height)
+    /// with the same value makes the scroll position exact.
+    #[must_use]
+    pub fn virtualized(mut self, estimated_row_height: f32) -> Self {
+        self.virtualization = Some(estimated_row_height);
+        self
+    }
+
+    /// Sets the number of leading columns of the [`Table`] that stay visible
+    /// when it is scrolled horizontally inside a
+    /// [`Scrollable`](iced::widget::Scrollable).
+    #[must_use]
+    pub fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
+
+    /// Sets whether the header of the [`Table`] stays visible when it is
+    /// scrolled vertically inside a [`Scrollable`](iced::widget::Scrollable).
+    #[must_use]
+    pub fn sticky_header(mut self, sticky: bool) -> Self {
+        self.sticky_header = sticky;
+        self
+    }
+
+    /// Sets the keys of the selected rows of the [`Table`].
+    #[must_use]
+    pub fn selection(mut self, selection: impl IntoIterator<Item = K>) -> Self {
+        self.selection = selection.into_iter().collect();
+        self
+    }
+
+    /// Sets the message that will be produced when the selection of the
+    /// [`Table`] changes.
+    ///
+    /// Rows are selected by clicking them, holding the command key toggles
+    /// a row and holding shift selects a range. The arrow keys move the
+    /// selection once the table was clicked.
+    #[must_use]
+    pub fn on_selection_change<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Vec<K>) -> Message,
+    {
+        self.on_selection_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets what the [`Table`] copies to the clipboard when it is focused and
+    /// the command key and C are pressed.
+    ///
+    /// By default the selected rows are copied as a whole, with the
+    /// [`export`](TableColumn::export) texts of their columns. With
+    /// [`CopyMode::Cell`] only the export text of the cell last clicked in
+    /// the row of the keyboard cursor is copied. Ranges of cells spanning
+    /// only some of the columns cannot be copied.
+    #[must_use]
+    pub fn copy_mode(mut self, mode: CopyMode) -> Self {
+        self.copy_mode = mode;
+        self
+    }
+
+    /// Replaces the cell of the row with the key `row` in `column` with the
+    /// editor of the column.
+    ///
+    /// Nothing is edited if the column has no [`editor`](TableColumn::editor)
+    /// or no row has the key.
+    #[must_use]
+    pub fn editing(mut self, row: &K, column: usize) -> Self {
+        if self.is_editable(column) {
+            self.editing = Some((row.clone(), column));
+        }
+        self
+    }
+
+    /// Sets the message that will be produced when a cell of the [`Table`]
+    /// is edited, with the key of its row and its column.
+    ///
+    /// Double-clicking an editable cell or pressing Enter on it starts the
+    /// editing, Enter commits it and Escape cancels it.
+    #[must_use]
+    pub fn on_cell_edit<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(K, usize, CellEdit) -> Message,
+    {
+        self.on_cell_edit = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the labels of the collapsed groups of the [`Table`], whose rows
+    /// are hidden below their group header row.
+    #[must_use]
+    pub fn collapsed(mut self, groups: impl IntoIterator<Item = String>) -> Self {
+        self.collapsed = groups.into_iter().collect();
+        self
+    }
+
+    /// Sets the message that will be produced when a group header row of the
+    /// [`Table`] is clicked, with the label of the group to collapse or
+    /// expand.
+    #[must_use]
+    pub fn on_group_toggle<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_group_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the disclosure caret of a
+    /// collapsed node of a tree [`Table`] is clicked, with the key of its row.
+    ///
+    /// The application is expected to load the children of the node if
+    /// needed and to pass its key to [`tree`](Self::tree) as expanded.
+    #[must_use]
+    pub fn on_expand<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(K) -> Message,
+    {
+        self.on_expand = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the disclosure caret of an
+    /// expanded node of a tree [`Table`] is clicked, with the key of its row.
+    #[must_use]
+    pub fn on_collapse<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(K) -> Message,
+    {
+        self.on_collapse = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the filter of a column is
+    /// edited, with the index of the column and the new [`FilterValue`].
+    ///
+    /// The filters of the columns, like a
+    /// [`text_filter`](TableColumn::text_filter), are shown in a filter row
+    /// below the header, which stays visible with a
+    /// [`sticky_header`](Self::sticky_header).
+    #[must_use]
+    pub fn on_filter_change<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize, FilterValue) -> Message,
+    {
+        if self.columns.iter().all(|column| column.filter.is_none()) {
+            return self;
+        }
+
+        let on_filter_change = Rc::new(f);
+        let cells = self
+            .columns
+            .iter_mut()
+            .enumerate()
+            .map(|(idx, column)| {
+                column.filter.take().map_or_else(
+                    || Space::new(Length::Shrink, Length::Shrink).into(),
+                    |filter| {
+                        let on_filter_change = Rc::clone(&on_filter_change);
+                        filter.map(move |value| on_filter_change(idx, value))
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut filters = TableRow::new(cells, 0);
+        if let Some(height) = self.row_height {
+            filters = filters.height(height);
+        }
+        if let Some(class) = &self.row_class {
+            filters.set_class(Rc::clone(class));
+        }
+
+        self.filters = Some(filters);
+        self
+    }
+
+    /// Sets the style of the header of the [`Table`].
+    #[must_use]
+    pub fn header_style(
+        mut self,
+        style: impl Fn(&Theme, Status) -> table_header::Style + 'a,
+    ) -> Self
+    where
+        <Theme as table_header::Catalog>::Class<'a>: From<StyleFn<'a, Theme, table_header::Style>>,
+    {
+        self.header = self.header.style(style);
+        self
+    }
+
+    /// Sets the class of the header of the [`Table`].
+    #[must_use]
+    pub fn header_class(
+        mut self,
+        class: impl Into<<Theme as table_header::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.header = self.header.class(class);
+        self
+    }
+
+    /// Sets the style of the rows of the [`Table`], which also receives the
+    /// position of a row in the table.
+    #[must_use]
+    pub fn row_style(self, style: impl Fn(&Theme, Status, usize) -> table_row::Style + 'a) -> Self
+    where
+        <Theme as table_row::Catalog>::Class<'a>: From<RowStyleFn<'a, Theme>>,
+    {
+        self.row_class(Box::new(style) as RowStyleFn<'a, Theme>)
+    }
+
+    /// Sets the class of the rows of the [`Table`].
+    #[must_use]
+    pub fn row_class(mut self, class: impl Into<<Theme as table_row::Catalog>::Class<'a>>) -> Self {
+        let class = Rc::new(class.into());
+        if let Some(filters) = &mut self.filters {
+            filters.set_class(Rc::clone(&class));
+        }
+        self.row_class = Some(class);
+        self
+    }
+
+    /// Returns the columns of the [`Table`].
+    #[must_use]
+    pub fn columns(&self) -> &[TableColumn<'a, T, Message, Theme, Renderer>] {
+        &self.columns
+    }
+
+    /// Returns whether the row at `line` is hidden in a collapsed group.
+    fn is_hidden(&self, line: usize) -> bool {
+        matches!(
+            self.lines[line],
+            Line::Entry { group: Some(group), .. } if self.collapsed.contains(&self.groups[group])
+        )
+    }
+
+    /// Returns the visible rows of the [`Table`] in the given format, with a
+    /// first line of the column titles.
+    ///
+    /// The rows are exported as displayed, in the order and with the columns
+    /// passed to the table, leaving out group header rows and the rows of
+    /// collapsed groups. Only the columns with an
+    /// [`export`](TableColumn::export) text are exported.
+    #[must_use]
+    pub fn export(&self, format: ExportFormat) -> String {
+        self.serialize(format, &self.entry_lines(), true)
+    }
+
+    /// Returns the rows at `lines` in the given format, preceded by the column
+    /// titles if `titles` is set.
+    fn serialize(&self, format: ExportFormat, lines: &[usize], titles: bool) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .filter_map(|column| column.export.as_ref().map(|text| (column, text)))
+            .collect::<Vec<_>>();
+
+        let titles = titles.then(|| {
+            columns
+                .iter()
+                .map(|(column, _)| column.title.clone())
+                .collect::<Vec<_>>()
+        });
+        let records = lines.iter().filter_map(|&line| match self.lines[line] {
+            Line::Entry { index, .. } => Some(
+                columns
+                    .iter()
+                    .map(|(_, text)| text(self.entries[index]))
+                    .collect(),
+            ),
+            Line::Group(_) => None,
+        });
+
+        export::write(format, titles.into_iter().chain(records))
+    }
+
+    /// Copies the selected rows to the clipboard as tab-separated values, or
+    /// the focused cell depending on the [`CopyMode`], when the command key
+    /// and C are pressed.
+    fn update_clipboard(
+        &self,
+        state: &TableState<K>,
+        event: &Event,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event else {
+            return event::Status::Ignored;
+        };
+
+        if !state.focused || !modifiers.command() || key.as_ref() != keyboard::Key::Character("c") {
+            return event::Status::Ignored;
+        }
+
+        let text = match self.copy_mode {
+            CopyMode::Rows => {
+                let mut lines = self
+                    .selection
+                    .iter()
+                    .filter_map(|key| match state.lines.get(key) {
+                        Some(&(line, Some(_))) => Some(line),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                lines.sort_unstable();
+
+                (!lines.is_empty()).then(|| self.serialize(ExportFormat::Tsv, &lines, false))
+            }
+            CopyMode::Cell => self.cell_text(state),
+        };
+
+        let Some(text) = text else {
+            return event::Status::Ignored;
+        };
+
+        clipboard.write(clipboard::Kind::Standard, text);
+        event::Status::Captured
+    }
+
+    /// Returns the export text of the cell last clicked in the row of the
+    /// keyboard cursor.
+    fn cell_text(&self, state: &TableState<K>) -> Option<String> {
+        let &(line, _) = state.lines.get(state.cursor.as_ref()?)?;
+        let Line::Entry { index, .. } = self.lines[line] else {
+            return None;
+        };
+        let text = self.columns.get(state.column?)?.export.as_ref()?;
+
+        Some(text(self.entries[index]))
+    }
+
+    /// Returns the positions of the visible rows displaying entries, which
+    /// can be selected.
+    fn entry_lines(&self) -> Vec<usize> {
+        (0..self.lines.len())
+            .filter(|&line| matches!(self.lines[line], Line::Entry { .. }) && !self.is_hidden(line))
+            .collect()
+    }
+
+    /// Indexes the keys of the rows in `state`, once per change of the
+    /// [`Table`] rather than on every input event.
+    fn index_keys(&self, state: &mut TableState<K>) {
+        state.keys.clear();
+        state.lines.clear();
+
+        for line in 0..self.lines.len() {
+            let Some(key) = self.key_of(line) else {
+                continue;
+            };
+            let position = (!self.is_hidden(line)).then(|| {
+                state.keys.push(key.clone());
+                state.keys.len() - 1
+            });
+            let _ = state.lines.insert(key.clone(), (line, position));
+        }
+    }
+
+    /// Returns the edited cell, if its row is in the [`Table`].
+    fn edited(&self, state: &TableState<K>) -> Option<(K, usize)> {
+        self.editing
+            .clone()
+            .filter(|(key, _)| state.lines.contains_key(key))
+    }
+
+    /// Returns the key of the row at `line`, or `None` for a group header row.
+    fn key_of(&self, line: usize) -> Option<&K> {
+        match self.lines.get(line)? {
+            Line::Entry { index, .. } => self.keys.get(*index),
+            Line::Group(_) => None,
+        }
+    }
+
+    /// Returns the lines of the built rows with their layouts.
+    fn row_layouts<'b>(&self, layout: Layout<'b>) -> Vec<(usize, Layout<'b>)> {
+        self.rows
+            .borrow()
+            .iter()
+            .map(|(line, _)| *line)
+            .zip(layout.children().skip(1))
+            .collect()
+    }
+
+    /// Produces the toggling of a group when its header row is clicked.
+    fn update_groups(
+        &self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(on_group_toggle) = &self.on_group_toggle else {
+            return event::Status::Ignored;
+        };
+
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        ) {
+            return event::Status::Ignored;
+        }
+
+        let group = self
+            .row_layouts(layout)
+            .into_iter()
+            .find(|(_, row)| cursor.is_over(row.bounds()))
+            .and_then(|(line, _)| match self.lines[line] {
+                Line::Group(group) => Some(group),
+                Line::Entry { .. } => None,
+            });
+
+        group.map_or(event::Status::Ignored, |group| {
+            shell.publish(on_group_toggle(self.groups[group].clone()));
+            event::Status::Captured
+        })
+    }
+
+    /// Produces the expanding or collapsing of a node of a tree table when
+    /// its disclosure caret is clicked.
+    fn update_tree(
+        &self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        ) {
+            return event::Status::Ignored;
+        }
+
+        let toggled = self
+            .row_layouts(layout)
+            .into_iter()
+            .find_map(|(line, layout)| {
+                let Line::Entry {
+                    depth,
+                    expanded: Some(expanded),
+                    ..
+                } = self.lines[line]
+                else {
+                    return None;
+                };
+                let cell = layout.children().next()?;
+
+                cursor
+                    .is_over(group::disclosure_bounds(cell.bounds(), depth))
+                    .then(|| (self.key_of(line).cloned(), expanded))
+            });
+
+        let Some((Some(key), expanded)) = toggled else {
+            return event::Status::Ignored;
+        };
+
+        let on_toggle = if expanded {
+            &self.on_collapse
+        } else {
+            &self.on_expand
+        };
+
+        on_toggle
+            .as_ref()
+            .map_or(event::Status::Ignored, |on_toggle| {
+                shell.publish(on_toggle(key));
+                event::Status::Captured
+            })
+    }
+
+    /// Updates the selection of the rows on clicks and arrow key presses.
+    fn update_selection(
+        &self,
+        state: &mut TableState<K>,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(on_selection_change) = &self.on_selection_change else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.focused = cursor.is_over(layout.bounds());
+
+                let Some((line, row)) = self
+                    .row_layouts(layout)
+                    .into_iter()
+                    .find(|(_, row)| cursor.is_over(row.bounds()))
+                else {
+                    return event::Status::Ignored;
+                };
+                state.column = row
+                    .children()
+                    .position(|cell| cursor.is_over(cell.bounds()));
+
+                let Some(&(_, Some(idx))) = self.key_of(line).and_then(|key| state.lines.get(key))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let ids = &state.keys;
+                let anchor = state.anchor.as_ref();
+                let anchored = anchor.is_some_and(|anchor| ids.contains(anchor));
+                let selection =
+                    selection::click(ids, &self.selection, idx, anchor, state.modifiers);
+                let key = ids[idx].clone();
+
+                if !state.modifiers.shift() || !anchored {
+                    state.anchor = Some(key.clone());
+                }
+                state.cursor = Some(key);
+
+                shell.publish(on_selection_change(selection));
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key @ (Named::ArrowUp | Named::ArrowDown)),
+                modifiers,
+                ..
+            }) if state.focused => {
+                let ids = &state.keys;
+                let (cursor, anchor) = (state.cursor.as_ref(), state.anchor.as_ref());
+                let anchored = anchor.is_some_and(|anchor| ids.contains(anchor));
+                let down = *key == Named::ArrowDown;
+
+                if let Some((idx, selection)) =
+                    selection::step(ids, cursor, anchor, down, *modifiers)
+                {
+                    let key = ids[idx].clone();
+
+                    if !modifiers.shift() || !anchored {
+                        state.anchor = Some(key.clone());
+                    }
+                    state.cursor = Some(key);
+
+                    shell.publish(on_selection_change(selection));
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Starts, commits and cancels the editing of cells on double-clicks and
+    /// key presses.
+    fn update_editing(
+        &self,
+        state: &mut TableState<K>,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(on_cell_edit) = &self.on_cell_edit else {
+            return event::Status::Ignored;
+        };
+        let editing = self.edited(state);
+        let edit = |(key, column): (K, usize), edit| on_cell_edit(key, column, edit);
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key @ (Named::Enter | Named::Escape)),
+                ..
+            }) => {
+                let message = match (editing, key) {
+                    (Some(cell), Named::Enter) => Some(edit(cell, CellEdit::Commit)),
+                    (Some(cell), _) => Some(edit(cell, CellEdit::Cancel)),
+                    (None, Named::Enter) if state.focused => state
+                        .cursor
+                        .clone()
+                        .filter(|key| state.lines.contains_key(key))
+                        .zip(state.column)
+                        .filter(|(_, column)| self.is_editable(*column))
+                        .map(|cell| edit(cell, CellEdit::Start)),
+                    (None, _) => None,
+                };
+
+                message.map_or(event::Status::Ignored, |message| {
+                    shell.publish(message);
+                    event::Status::Captured
+                })
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+                let cell = self
+                    .row_layouts(layout)
+                    .into_iter()
+                    .find(|(_, row)| row.bounds().contains(position))
+                    .and_then(|(row, layout)| {
+                        layout
+                            .children()
+                            .position(|cell| cell.bounds().contains(position))
+                            .map(|column| (row, column))
+                    });
+                let clicked = cell.and_then(|(row, column)| Some((self.key_of(row)?, column)));
+
+                if let Some(editing) = editing
+                    .as_ref()
+                    .filter(|(key, column)| clicked != Some((key, *column)))
+                {
+                    shell.publish(edit(editing.clone(), CellEdit::Commit));
+                }
+
+                state.focused = cursor.is_over(layout.bounds());
+
+                let Some((row, column)) = cell else {
+                    state.last_click = None;
+                    return event::Status::Ignored;
+                };
+
+                let click =
+                    mouse::click::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+                state.cursor = self.key_of(row).cloned();
+                state.column = Some(column);
+
+                let is_double = matches!(click.kind(), mouse::click::Kind::Double);
+                if is_double && editing.is_none() && self.is_editable(column) {
+                    if let Some(key) = self.key_of(row) {
+                        shell.publish(edit((key.clone(), column), CellEdit::Start));
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Returns whether the cells of the column at `column` can be edited.
+    fn is_editable(&self, column: usize) -> bool {
+        self.columns
+            .get(column)
+            .is_some_and(TableColumn::is_editable)
+    }
+
+    /// Returns the width of the widest content of the header cell and the
+    /// built rows of `column`, which fill columns take up when the width of
+    /// the table is unbounded and a column is fit to on a divider
+    /// double-click.
+    ///
+    /// Only the rows in the window of a virtualized table and the rows of
+    /// expanded groups are measured.
+    fn content_width(&self, trees: &mut [Tree], renderer: &Renderer, column: usize) -> f32 {
+        let Some((header_tree, row_trees)) = trees.split_first_mut() else {
+            return MIN_COLUMN_WIDTH;
+        };
+        let rows = self.rows.borrow();
+        let widths = rows
+            .iter()
+            .zip(row_trees)
+            .filter_map(|((_, row), tree)| row.cell_width(tree, renderer, column));
+
+        self.header
+            .cell_width(header_tree, renderer, column)
+            .into_iter()
+            .chain(widths)
+            .fold(MIN_COLUMN_WIDTH, f32::max)
+    }
+
+    /// Returns the number of frozen columns and their total width.
+    fn frozen(&self, layout: Layout<'_>) -> (usize, f32) {
+        let count = self.frozen_columns.min(self.columns.len());
+        let width = layout.children().next().map_or(0.0, |header| {
+            header
+                .children()
+                .take(count)
+                .map(|cell| cell.bounds().width)
+                .sum()
+        });
+
+        (count, width)
+    }
+
+    /// Returns the layout of the filter row, the last child of the layout.
+    fn filter_layout<'b>(&self, layout: Layout<'b>) -> Option<Layout<'b>> {
+        self.filters
+            .as_ref()
+            .and_then(|_| layout.children().next_back())
+    }
+
+    /// Returns the height of the header and the filter row.
+    fn head_height(&self, layout: Layout<'_>) -> f32 {
+        layout
+            .children()
+            .next()
+            .into_iter()
+            .chain(self.filter_layout(layout))
+            .map(|layout| layout.bounds().height)
+            .sum()
+    }
+
+    /// Returns how far the frozen columns and the sticky header are moved to
+    /// stay inside the `viewport`.
+    fn sticky_offset(&self, layout: Layout<'_>, viewport: &Rectangle) -> Vector {
+        let bounds = layout.bounds();
+        let header_height = self.head_height(layout);
+        let (count, frozen_width) = self.frozen(layout);
+
+        let x = if count > 0 {
+            (viewport.x - bounds.x).clamp(0.0, (bounds.width - frozen_width).max(0.0))
+        } else {
+            0.0
+        };
+        let y = if self.sticky_header {
+            (viewport.y - bounds.y).clamp(0.0, (bounds.height - header_height).max(0.0))
+        } else {
+            0.0
+        };
+
+        Vector::new(x, y)
+    }
+
+    /// Returns the cursors of the header and the rows, moved onto the frozen
+    /// columns and the sticky header if they are drawn below the cursor.
+    fn cursors(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> (mouse::Cursor, mouse::Cursor) {
+        let Some(mut position) = cursor.position() else {
+            return (cursor, cursor);
+        };
+
+        let offset = self.sticky_offset(layout, viewport);
+        let (_, frozen_width) = self.frozen(layout);
+        let header_height = self.head_height(layout);
+
+        if offset.x > 0.0 && position.x < viewport.x + frozen_width {
+            position.x -= offset.x;
+        }
+
+        if offset.y > 0.0 && position.y < viewport.y + header_height {
+            position.y -= offset.y;
+            (
+                mouse::Cursor::Available(position),
+                mouse::Cursor::Unavailable,
+            )
+        } else {
+            (
+                mouse::Cursor::Available(position),
+                mouse::Cursor::Available(position),
+            )
+        }
+    }
+
+    /// Draws the disclosure caret of the row with the `layout` if it is a
+    /// group header row or a node of a tree table with children.
+    fn draw_disclosure(
+        &self,
+        renderer: &mut Renderer,
+        line: Line,
+        layout: Layout<'_>,
+        color: Color,
+    ) {
+        let (depth, expanded) = match line {
+            Line::Group(group) => (0, !self.collapsed.contains(&self.groups[group])),
+            Line::Entry {
+                depth,
+                expanded: Some(expanded),
+                ..
+            } => (depth, expanded),
+            Line::Entry { .. } => return,
+        };
+
+        if let Some(cell) = layout.children().next() {
+            let bounds = group::disclosure_bounds(cell.bounds(), depth);
+            group::draw_disclosure(renderer, bounds, expanded, color);
+        }
+    }
+
+    /// Returns the header, the built `rows` and the filter row of the
+    /// [`Table`] in layout order.
+    ///
+    /// The filter row comes last so that the rows keep their positions
+    /// after the header, it is laid out between the header and the rows.
+    fn elements<'b>(
+        &'b self,
+        rows: &'b Rows<'a, Message, Theme, Renderer, K>,
+    ) -> Vec<&'b dyn Widget<Message, Theme, Renderer>> {
+        std::iter::once(&self.header as &dyn Widget<Message, Theme, Renderer>)
+            .chain(
+                rows.iter()
+                    .map(|(_, row)| row as &dyn Widget<Message, Theme, Renderer>),
+            )
+            .chain(
+                self.filters
+                    .iter()
+                    .map(|filters| filters as &dyn Widget<Message, Theme, Renderer>),
+            )
+            .collect()
+    }
+
+    /// Returns the header, the built rows and the filter row of the
+    /// [`Table`] in layout order.
+    fn elements_mut(&mut self) -> Vec<&mut (dyn Widget<Message, Theme, Renderer> + 'a)> {
+        std::iter::once(&mut self.header as &mut (dyn Widget<Message, Theme, Renderer> + 'a))
+            .chain(
+                self.rows
+                    .get_mut()
+                    .iter_mut()
+                    .map(|(_, row)| row as &mut (dyn Widget<Message, Theme, Renderer> + 'a)),
+            )
+            .chain(
+                self.filters
+                    .iter_mut()
+                    .map(|filters| filters as &mut (dyn Widget<Message, Theme, Renderer> + 'a)),
+            )
+            .collect()
+    }
+}
+
+/// The state of a [`Table`] with row keys of type `K`.
+#[derive(Debug)]
+struct TableState<K> {
+    /// The state of the column resizing.
+    header: TableHeaderState,
+    /// The widths of the columns resized by the user.
+    overrides: Vec<Option<f32>>,
+    /// The top and height of the visible part of a virtualized table,
+    /// relative to its first row.
+    visible: Option<(f32, f32)>,
+    /// The vertical range, relative to the first row, in which the rows of a
+    /// virtualized table are laid out.
+    laid_out: Option<Range<f32>>,
+    /// The measured heights of the rows of a virtualized table.
+    row_heights: Vec<Option<f32>>,
+    /// The lines of the rows whose trees are kept.
+    built: Vec<usize>,
+    /// The key of the row a shift-click selects the range from.
+    anchor: Option<K>,
+    /// The key of the row last moved to with a click or the arrow keys.
+    cursor: Option<K>,
+    /// The current keyboard modifiers.
+    modifiers: keyboard::Modifiers,
+    /// Whether the arrow keys move the selection and the selected rows can
+    /// be copied.
+    focused: bool,
+    /// The column last clicked, edited when Enter is pressed.
+    column: Option<usize>,
+    /// The last click, to detect double-clicks.
+    last_click: Option<mouse::click::Click>,
+    /// The last click on a column divider, to detect double-clicks.
+    divider_click: Option<mouse::click::Click>,
+    /// The cell whose editor was focused.
+    edited: Option<(K, usize)>,
+    /// The keys of the shown rows displaying entries, which can be selected.
+    keys: Vec<K>,
+    /// The lines of the rows displaying entries by key, with their position
+    /// in `keys` if they are shown.
+    lines: HashMap<K, (usize, Option<usize>)>,
+}
+
+impl<K> Default for TableState<K> {
+    fn default() -> Self {
+        Self {
+            header: TableHeaderState::default(),
+            overrides: Vec::new(),
+            visible: None,
+            laid_out: None,
+            row_heights: Vec::new(),
+            built: Vec::new(),
+            anchor: None,
+            cursor: None,
+            modifiers: keyboard::Modifiers::default(),
+            focused: false,
+            column: None,
+            last_click: None,
+            divider_click: None,
+            edited: None,
+            keys: Vec::new(),
+            lines: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer, K> Widget<Message, Theme, Renderer>
+    for Table<'a, T, Message, Theme, Renderer, K>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a + table_header::Catalog + table_row::Catalog + text::Catalog,
+    K: 'static + Clone + Eq + Hash,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<TableState<K>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = TableState::<K>::default();
+        self.index_keys(&mut state);
+
+        tree::State::new(state)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        // The rows are built when the table is laid out.
+        self.elements(&Vec::new())
+            .into_iter()
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut();
+        self.index_keys(state);
+
+        let _ = self.build_rows(state, &mut tree.children);
+        tree.diff_children(&self.elements(&self.rows.borrow()));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let state = tree.state.downcast_mut::<TableState<K>>();
+        state.overrides.resize(self.columns.len(), None);
+        state.row_heights.resize(self.lines.len(), None);
+
+        if self.virtualization.is_some() && state.visible.is_none() {
+            let max_height = limits.max().height;
+            state.visible = Some((
+                0.0,
+                if max_height.is_finite() {
+                    max_height
+                } else {
+                    DEFAULT_VIRTUAL_HEIGHT
+                },
+            ));
+        }
+
+        if self.build_rows(state, &mut tree.children) {
+            tree.diff_children(&self.elements(&self.rows.borrow()));
+        }
+
+        let lengths = self
+            .columns
+            .iter()
+            .map(|column| column.width)
+            .collect::<Vec<_>>();
+        let state = tree.state.downcast_mut::<TableState<K>>();
+        let widths = resolve_widths(&lengths, &state.overrides, limits.max().width, |column| {
+            self.content_width(&mut tree.children, renderer, column)
+        });
+
+        let rows = self.rows.borrow();
+        let mut trees = tree.children.iter_mut();
+        let mut nodes = Vec::with_capacity(rows.len() + 2);
+
+        if let Some(tree) = trees.next() {
+            nodes.push(self.header.layout_columns(tree, renderer, &widths));
+        }
+
+        let mut head_height = nodes.first().map_or(0.0, |node| node.size().height);
+
+        // The tree of the filter row is only taken if there is one, the last
+        // tree belongs to the last row otherwise.
+        let filter_node = self.filters.as_ref().and_then(|filters| {
+            let tree = trees.next_back()?;
+            Some(
+                filters
+                    .layout_columns(tree, renderer, &widths)
+                    .move_to((0.0, head_height)),
+            )
+        });
+        head_height += filter_node.as_ref().map_or(0.0, |node| node.size().height);
+
+        let mut built = rows.iter().zip(trees).peekable();
+        let mut laid_out = f32::NEG_INFINITY..f32::INFINITY;
+        let mut height = 0.0;
+
+        for line in (0..self.lines.len()).filter(|&line| !self.is_hidden(line)) {
+            let Some(((_, row), tree)) = built.next_if(|((built, _), _)| *built == line) else {
+                // Rows outside of the window of a virtualized table take up
+                // their last measured or their estimated height.
+                let row_height = state.row_heights[line].or(self.virtualization);
+
+                if nodes.len() == 1 {
+                    height += row_height.unwrap_or_default();
+                    laid_out.start = height;
+                } else {
+                    if laid_out.end.is_infinite() {
+                        laid_out.end = height;
+                    }
+                    height += row_height.unwrap_or_default();
+                }
+                continue;
+            };
+
+            let node = row
+                .layout_columns(tree, renderer, &widths)
+                .move_to((0.0, head_height + height));
+            state.row_heights[line] = Some(node.size().height);
+
+            height += node.size().height;
+            nodes.push(node);
+        }
+
+        state.laid_out = self.virtualization.map(|_| laid_out);
+        nodes.extend(filter_node);
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(widths.iter().sum(), head_height + height),
+        );
+
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let (header_cursor, rows_cursor) = self.cursors(layout, cursor, viewport);
+        let offset = self.sticky_offset(layout, viewport);
+        let (frozen, frozen_width) = self.frozen(layout);
+
+        let rows = self.rows.borrow();
+        let visible_rows = || {
+            rows.iter()
+                .zip(tree.children.iter().skip(1))
+                .zip(layout.children().skip(1))
+                .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        };
+
+        for (((line, row), state), layout) in visible_rows() {
+            row.draw(state, renderer, theme, style, layout, rows_cursor, viewport);
+            self.draw_disclosure(renderer, self.lines[*line], layout, style.text_color);
+        }
+
+        let frozen_bounds = Rectangle {
+            width: frozen_width,
+            ..*viewport
+        };
+
+        if offset.x > 0.0 {
+            let viewport = *viewport - Vector::new(offset.x, 0.0);
+
+            renderer.with_layer(frozen_bounds, |renderer| {
+                renderer.with_translation(Vector::new(offset.x, 0.0), |renderer| {
+                    for (((line, row), state), layout) in visible_rows() {
+                        row.draw_cells(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            layout,
+                            rows_cursor,
+                            &viewport,
+                            frozen,
+                        );
+                        self.draw_disclosure(renderer, self.lines[*line], layout, style.text_color);
+                    }
+                });
+            });
+        }
+
+        if let (Some(header_tree), Some(header_layout)) =
+            (tree.children.first(), layout.children().next())
+        {
+            // Draws the header and the filter row with the first `count` cells.
+            let draw_head = |renderer: &mut Renderer, viewport: &Rectangle, count: usize| {
+                self.header.draw_cells(
+                    header_tree,
+                    renderer,
+                    theme,
+                    style,
+                    header_layout,
+                    header_cursor,
+                    viewport,
+                    count,
+                );
+
+                if let (Some(filters), Some(filter_tree), Some(filter_layout)) = (
+                    &self.filters,
+                    tree.children.last(),
+                    self.filter_layout(layout),
+                ) {
+                    filters.draw_cells(
+                        filter_tree,
+                        renderer,
+                        theme,
+                        style,
+                        filter_layout,
+                        header_cursor,
+                        viewport,
+                        count,
+                    );
+                }
+            };
+
+            let head_bounds = Rectangle {
+                height: self.head_height(layout),
+                ..header_layout.bounds()
+            } + Vector::new(0.0, offset.y);
+
+            if offset == Vector::ZERO {
+                draw_head(renderer, viewport, usize::MAX);
+            } else if let Some(clip) = head_bounds.intersection(viewport) {
+                let viewport = *viewport - offset;
+
+                renderer.with_layer(clip, |renderer| {
+                    renderer.with_translation(Vector::new(0.0, offset.y), |renderer| {
+                        draw_head(renderer, &viewport, usize::MAX);
+                    });
+                });
+
+                if let Some(clip) = clip.intersection(&frozen_bounds) {
+                    renderer.with_layer(clip, |renderer| {
+                        renderer.with_translation(offset, |renderer| {
+                            draw_head(renderer, &viewport, frozen);
+                        });
+                    });
+                }
+            }
+        }
+
+        let state = tree.state.downcast_ref::<TableState<K>>();
+
+        if let (Some(header_layout), Some(bounds)) = (
+            layout.children().next(),
+            layout.bounds().intersection(viewport),
+        ) {
+            let cells = self.header.cells(header_layout);
+            self.header
+                .draw_drop_indicator(&state.header, renderer, theme, &cells, bounds);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<TableState<K>>();
+
+        if self.virtualization.is_some() {
+            let top = viewport.y - layout.position().y - self.head_height(layout);
+            state.visible = Some((top, viewport.height));
+
+            let is_laid_out = state
+                .laid_out
+                .as_ref()
+                .is_some_and(|range| range.start <= top && top + viewport.height <= range.end);
+
+            if !is_laid_out {
+                shell.invalidate_layout();
+            }
+        }
+
+        let (header_cursor, rows_cursor) = self.cursors(layout, cursor, viewport);
+
+        if let (Some(header_layout), Some(cursor_position)) =
+            (layout.children().next(), header_cursor.position())
+        {
+            let leeway = f32::from(self.leeway);
+            let header_bounds = header_layout.bounds().expand(leeway);
+
+            if header_bounds.contains(cursor_position) || state.header.resize.resizing {
+                let dividers = header_layout
+                    .children()
+                    .enumerate()
+                    .take(self.columns.len().saturating_sub(1))
+                    .map(|(idx, layout)| (idx, layout.bounds().x + layout.bounds().width))
+                    .collect::<Vec<_>>();
+
+                state
+                    .header
+                    .resize
+                    .hover_dividers(&dividers, cursor_position.x, leeway);
+            } else {
+                state.header.resize.resize_hovering = false;
+            }
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                    if state.header.resize.resize_hovering =>
+                {
+                    let idx = state.header.resize.resizing_idx;
+                    let click = mouse::click::Click::new(
+                        cursor_position,
+                        mouse::Button::Left,
+                        state.divider_click,
+                    );
+                    state.divider_click = Some(click);
+
+                    if matches!(click.kind(), mouse::click::Kind::Double) {
+                        state.overrides[idx] =
+                            Some(self.content_width(&mut tree.children, renderer, idx));
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+
+                    let width = |idx| {
+                        header_layout
+                            .children()
+                            .nth(idx)
+                            .map_or(0.0, |layout| layout.bounds().width)
+                    };
+                    state
+                        .header
+                        .resize
+                        .start_resize(cursor_position, width(idx), width(idx + 1));
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                    if state.header.resize.resizing =>
+                {
+                    let idx = state.header.resize.resizing_idx;
+                    let (left_width, right_width) = state
+                        .header
+                        .resize
+                        .resized_widths(position.x, MIN_COLUMN_WIDTH);
+                    state.overrides[idx] = Some(left_width);
+                    state.overrides[idx + 1] = Some(right_width);
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                    if state.header.resize.resizing =>
+                {
+                    let _ = state.header.resize.finish_resize();
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(header_layout) = layout.children().next() {
+            if self.header.has_column_actions() {
+                let cells = self.header.cells(header_layout);
+                let (status, action) = state.header.update_columns(&event, header_cursor, &cells);
+
+                if let Some(action) = action {
+                    self.header.publish_action(action, shell);
+                }
+
+                if status == event::Status::Captured {
+                    return status;
+                }
+            }
+        }
+
+        let editing = self.edited(state);
+        if state.edited != editing {
+            let rows = self.rows.get_mut();
+            let built = editing
+                .as_ref()
+                .and_then(|(key, _)| state.lines.get(key))
+                .and_then(|&(line, _)| rows.iter().position(|(built, _)| *built == line));
+
+            // The editor of a row outside of the window is focused once the
+            // row is built.
+            if let Some(idx) = built {
+                if let (Some(row_tree), Some(row_layout)) = (
+                    tree.children.get_mut(idx + 1),
+                    layout.children().nth(idx + 1),
+                ) {
+                    rows[idx]
+                        .1
+                        .operate(row_tree, row_layout, renderer, &mut focus_first());
+                }
+            }
+            if built.is_some() || editing.is_none() {
+                state.edited.clone_from(&editing);
+            }
+        }
+
+        if editing.is_some() {
+            let status = self.update_editing(
+                tree.state.downcast_mut::<TableState<K>>(),
+                &event,
+                layout,
+                rows_cursor,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        // The header and the filter row come first and last in layout order.
+        let row_count = self.rows.get_mut().len();
+        let is_head = |idx| idx == 0 || idx > row_count;
+
+        let status = self
+            .elements_mut()
+            .into_iter()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .map(|(idx, ((element, state), layout))| {
+                element.on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    if is_head(idx) {
+                        header_cursor
+                    } else {
+                        rows_cursor
+                    },
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let toggled = self
+            .update_groups(&event, layout, rows_cursor, shell)
+            .merge(self.update_tree(&event, layout, rows_cursor, shell));
+
+        if toggled == event::Status::Captured {
+            return toggled;
+        }
+
+        let state = tree.state.downcast_mut::<TableState<K>>();
+
+        let editing = if editing.is_none() {
+            self.update_editing(state, &event, layout, rows_cursor, shell)
+        } else {
+            event::Status::Ignored
+        };
+
+        editing
+            .merge(self.update_selection(state, &event, layout, rows_cursor, shell))
+            .merge(self.update_clipboard(state, &event, clipboard))
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<TableState<K>>();
+
+        if state.header.resize.resizing || state.header.resize.resize_hovering {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if state.header.dragging {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let (header_cursor, rows_cursor) = self.cursors(layout, cursor, viewport);
+        let rows = self.rows.borrow();
+        let is_head = |idx| idx == 0 || idx > rows.len();
+
+        self.elements(&rows)
+            .into_iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .map(|(idx, ((element, state), layout))| {
+                let cursor = if is_head(idx) {
+                    header_cursor
+                } else {
+                    rows_cursor
+                };
+                element.mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .fold(mouse::Interaction::default(), mouse::Interaction::max)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        for ((element, state), layout) in self
+            .elements(&self.rows.borrow())
+            .into_iter()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            element.operate(state, layout, renderer, operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .elements_mut()
+            .into_iter()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((element, state), layout)| {
+                element.overlay(state, layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer, K> From<Table<'a, T, Message, Theme, Renderer, K>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    K: 'static + Clone + Eq + Hash,
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a + table_header::Catalog + table_row::Catalog + text::Catalog,
+{
+    fn from(table: Table<'a, T, Message, Theme, Renderer, K>) -> Self {
+        Element::new(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CellEdit, CopyMode, Table, TableColumn};
+    use iced::{
+        advanced::{clipboard, layout::Limits, widget::Tree, Clipboard, Layout, Shell, Widget},
+        keyboard::{self, key},
+        mouse,
+        widget::text,
+        Event, Rectangle, Size,
+    };
+
+    /// A clipboard keeping the last text written to it.
+    #[derive(Default)]
+    struct Recorder(Option<String>);
+
+    impl Clipboard for Recorder {
+        fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+            self.0.clone()
+        }
+
+        fn write(&mut self, _kind: clipboard::Kind, contents: String) {
+            self.0 = Some(contents);
+        }
+    }
+
+    /// Returns the event of pressing `key` with the given modifiers.
+    fn key_press(key: keyboard::Key, physical: key::Code, modifiers: keyboard::Modifiers) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key,
+            physical_key: key::Physical::Code(physical),
+            location: keyboard::Location::Standard,
+            modifiers,
+            text: None,
+        })
+    }
+
+    #[test]
+    fn commit_value_test() {
+        let names = [String::from("Alice"), String::from("Bob")];
+        let mut table: Table<'_, String, (usize, usize, CellEdit), iced::Theme, ()> = Table::new(
+            vec![TableColumn::new("Name", |name: &String| text(name.clone()))
+                .editor(|name: &String| text(name.clone()))],
+            &names,
+        )
+        .on_cell_edit(|row, column, edit| (row, column, edit))
+        .editing(&1, 0);
+
+        let mut tree = Tree::new(&table as &dyn Widget<_, _, _>);
+        let node = table.layout(
+            &mut tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        let mut messages = Vec::new();
+        let _ = table.on_event(
+            &mut tree,
+            key_press(
+                keyboard::Key::Named(key::Named::Enter),
+                key::Code::Enter,
+                keyboard::Modifiers::default(),
+            ),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::new(400.0, 400.0)),
+        );
+
+        assert_eq!(messages, vec![(1, 0, CellEdit::Commit)]);
+    }
+
+    #[test]
+    fn copy_cell_test() {
+        let names = [String::from("Alice"), String::from("Bob")];
+        let mut table: Table<'_, String, (), iced::Theme, ()> = Table::new(
+            vec![
+                TableColumn::new("Name", |name: &String| text(name.clone())).export(String::clone),
+                TableColumn::new("Length", |name: &String| text(name.len()))
+                    .export(|name: &String| name.len().to_string()),
+            ],
+            &names,
+        )
+        .row_height(20)
+        .selection([0])
+        .on_selection_change(|_| ())
+        .copy_mode(CopyMode::Cell);
+
+        let viewport = Rectangle::with_size(Size::new(400.0, 400.0));
+        let mut tree = Tree::new(&table as &dyn Widget<_, _, _>);
+        let node = table.layout(&mut tree, &(), &Limits::new(Size::ZERO, viewport.size()));
+        let cell = Layout::new(&node)
+            .children()
+            .nth(2)
+            .and_then(|row| row.children().nth(1))
+            .expect("The second row should have a second cell")
+            .bounds();
+
+        let mut clipboard = Recorder::default();
+        for (event, cursor) in [
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                mouse::Cursor::Available(cell.center()),
+            ),
+            (
+                key_press(
+                    keyboard::Key::Character("c".into()),
+                    key::Code::KeyC,
+                    keyboard::Modifiers::COMMAND,
+                ),
+                mouse::Cursor::Available(cell.center()),
+            ),
+        ] {
+            let _ = table.on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                cursor,
+                &(),
+                &mut clipboard,
+                &mut Shell::new(&mut Vec::new()),
+                &viewport,
+            );
+        }
+
+        assert_eq!(clipboard.0.as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn arrow_key_skips_collapsed_rows_test() {
+        let names = [
+            String::from("Alice"),
+            String::from("Bob"),
+            String::from("Carol"),
+        ];
+        let mut table: Table<'_, String, Vec<usize>, iced::Theme, ()> = Table::new(
+            vec![TableColumn::new("Name", |name: &String| text(name.clone()))],
+            &names,
+        )
+        .row_height(20)
+        .group_by(|name| String::from(if name.len() > 3 { "Long" } else { "Short" }))
+        .collapsed([String::from("Short")])
+        .on_selection_change(|selection| selection);
+
+        let viewport = Rectangle::with_size(Size::new(400.0, 400.0));
+        let mut tree = Tree::new(&table as &dyn Widget<_, _, _>);
+        let node = table.layout(&mut tree, &(), &Limits::new(Size::ZERO, viewport.size()));
+        let alice = Layout::new(&node)
+            .children()
+            .nth(2)
+            .expect("The first group should have a row")
+            .bounds();
+
+        let mut messages = Vec::new();
+        for event in [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            key_press(
+                keyboard::Key::Named(key::Named::ArrowDown),
+                key::Code::ArrowDown,
+                keyboard::Modifiers::default(),
+            ),
+        ] {
+            let _ = table.on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(alice.center()),
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+                &viewport,
+            );
+        }
+
+        assert_eq!(messages, vec![vec![0], vec![2]]);
+    }
+
+    #[test]
+    fn virtualized_window_test() {
+        let numbers = (0..1000).collect::<Vec<usize>>();
+        let mut table: Table<'_, usize, (), iced::Theme, ()> = Table::new(
+            vec![TableColumn::new("Number", |number: &usize| text(*number))],
+            &numbers,
+        )
+        .row_height(20)
+        .virtualized(20.0);
+
+        let limits = Limits::new(Size::ZERO, Size::new(400.0, 200.0));
+        let mut tree = Tree::new(&table as &dyn Widget<_, _, _>);
+        let node = table.layout(&mut tree, &(), &limits);
+
+        // The visible 200 pixels and a screen below them.
+        assert_eq!(tree.children.len(), 1 + 21);
+        assert_eq!(node.children().len(), 1 + 21);
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let _ = table.on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::CursorLeft),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::new(iced::Point::new(0.0, 10_000.0), Size::new(400.0, 200.0)),
+        );
+        assert!(shell.is_layout_invalid());
+
+        let node = table.layout(&mut tree, &(), &limits);
+        let mut children = Layout::new(&node).children();
+        let header = children
+            .next()
+            .expect("The header should be laid out")
+            .bounds();
+        let first_row = children.next().expect("A row should be laid out").bounds();
+
+        // A screen above and below the visible 200 pixels, starting with the
+        // row covering the top of the screen above.
+        assert!(tree.children.len() <= 1 + 32);
+        assert!(first_row.y <= 9_800.0 && first_row.y + first_row.height >= 9_800.0);
+        assert_eq!((first_row.y - header.height) % 20.0, 0.0);
+    }
+}
@@ -7,7 +7,7 @@
 //! *This API requires the following crate features to be activated: tabs*
 
 pub mod tab_bar_position;
-pub use crate::tab_bar::Position;
+pub use crate::tab_bar::{Overflow, Position};
 use crate::{
     style::{
         tab_bar::{Catalog, Style},
@@ -29,7 +29,7 @@ use iced::{
     },
     event,
     mouse::{self, Cursor},
-    widget::{text, Row},
+    widget::{button, container, text, Row},
     Element, Event, Font, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
 };
 
@@ -200,6 +200,28 @@ where
         self
     }
 
+    /// Sets the message that will be produced when a tab of the [`TabBar`]
+    /// is dragged to a new position, with its id and its new index.
+    ///
+    /// Setting this enables dragging the tabs. The order of the pushed tabs
+    /// has to be updated accordingly.
+    #[must_use]
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(TabId, usize) -> Message,
+    {
+        self.tab_bar = self.tab_bar.on_reorder(on_reorder);
+        self
+    }
+
+    /// Sets how the tabs are handled when they do not fit the width of the
+    /// [`TabBar`].
+    #[must_use]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.tab_bar = self.tab_bar.overflow(overflow);
+        self
+    }
+
     /// Pushes a [`TabLabel`] along with the tabs
     /// content to the [`Tabs`].
     #[must_use]
@@ -216,6 +238,31 @@ where
         self
     }
 
+    /// Pushes a tab labeled by any element along with the tabs content to
+    /// the [`Tabs`].
+    ///
+    /// The menu of the hidden tabs lists the tab with the `title`.
+    #[must_use]
+    pub fn push_element<L, E>(
+        mut self,
+        id: TabId,
+        label: L,
+        title: impl Into<String>,
+        element: E,
+    ) -> Self
+    where
+        L: Into<Element<'a, Message, Theme, Renderer>>,
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.tab_bar = self
+            .tab_bar
+            .push_element(id.clone(), label, title)
+            .set_position(self.tab_icon_position);
+        self.tabs.push(element.into());
+        self.indices.push(id);
+        self
+    }
+
     /// Sets the active tab of the [`Tabs`] using the ``TabId``.
     #[must_use]
     pub fn set_active_tab(mut self, id: &TabId) -> Self {
@@ -301,6 +348,26 @@ where
     }
 }
 
+impl<'a, Message, TabId, Theme, Renderer> Tabs<'a, Message, TabId, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: 'a + Catalog + text::Catalog + button::Catalog + container::Catalog,
+    <Theme as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    <Theme as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    TabId: Eq + Clone,
+{
+    /// Adds a button at the end of the overflowing tab strip of the [`TabBar`]
+    /// opening a menu of the tabs hidden by scrolling.
+    ///
+    /// This sets the overflow to [`Overflow::Scroll`].
+    #[must_use]
+    pub fn overflow_menu(mut self) -> Self {
+        self.tab_bar = self.tab_bar.overflow_menu();
+        self
+    }
+}
+
 impl<'a, Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Tabs<'a, Message, TabId, Theme, Renderer>
 where
@@ -429,7 +496,7 @@ where
         };
 
         let status_tab_bar = self.tab_bar.on_event(
-            &mut Tree::empty(),
+            &mut state.children[0],
             event.clone(),
             tab_bar_layout,
             cursor,
@@ -479,7 +546,7 @@ where
 
         let mut mouse_interaction = mouse::Interaction::default();
         let new_mouse_interaction = self.tab_bar.mouse_interaction(
-            &Tree::empty(),
+            &state.children[0],
             tab_bar_layout,
             cursor,
             viewport,
@@ -539,7 +606,7 @@ where
         };
 
         self.tab_bar.draw(
-            &Tree::empty(),
+            &state.children[0],
             renderer,
             theme,
             style,
@@ -580,25 +647,38 @@ where
         renderer: &Renderer,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        let layout = match self.tab_bar_position {
-            TabBarPosition::Top => layout.children().nth(1),
-            TabBarPosition::Bottom => layout.children().next(),
+        let mut children = layout.children();
+        let (tab_bar_layout, layout) = match self.tab_bar_position {
+            TabBarPosition::Top => (children.next(), children.next()),
+            TabBarPosition::Bottom => {
+                let layout = children.next();
+                (children.next(), layout)
+            }
         };
 
-        layout.and_then(|layout| {
-            let idx = self.tab_bar.get_active_tab_idx();
+        let idx = self.tab_bar.get_active_tab_idx();
+        let (tab_bar_state, tabs_state) = state.children.split_at_mut(1);
+        let tab_bar_overlay = tab_bar_layout.and_then(|tab_bar_layout| {
+            self.tab_bar
+                .overlay(&mut tab_bar_state[0], tab_bar_layout, renderer, translation)
+        });
+
+        let content_overlay = layout.and_then(|layout| {
             self.tabs
                 .get_mut(idx)
                 .map(Element::as_widget_mut)
                 .and_then(|w| {
                     w.overlay(
-                        &mut state.children[1].children[idx],
+                        &mut tabs_state[0].children[idx],
                         layout,
                         renderer,
                         translation,
                     )
                 })
-        })
+        });
+
+        let children: Vec<_> = tab_bar_overlay.into_iter().chain(content_overlay).collect();
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
     }
 
     fn operate(
@@ -14,6 +14,8 @@ use iced::{
 };
 use std::marker::PhantomData;
 
+use crate::core::breakpoints::Breakpoints;
+
 /// A container that distributes its contents horizontally.
 #[allow(missing_debug_implementations)]
 pub struct Wrap<'a, Message, Direction, Theme = iced::Theme, Renderer = iced::Renderer> {
@@ -21,6 +23,8 @@ pub struct Wrap<'a, Message, Direction, Theme = iced::Theme, Renderer = iced::Re
     pub elements: Vec<Element<'a, Message, Theme, Renderer>>,
     /// The alignment of the [`Wrap`].
     pub alignment: Alignment,
+    /// The distribution of the free space of each line of the [`Wrap`].
+    pub justify: Justify,
     /// The width of the [`Wrap`].
     pub width: Length,
     /// The height of the [`Wrap`].
@@ -37,6 +41,9 @@ pub struct Wrap<'a, Message, Direction, Theme = iced::Theme, Renderer = iced::Re
     pub line_spacing: Pixels,
     /// The minimal length of each line of the [`Wrap`].
     pub line_minimal_length: f32,
+    /// The [`Arrangement`]s replacing the spacing and alignment from
+    /// a minimum available width on.
+    pub breakpoints: Breakpoints<Arrangement>,
     #[allow(clippy::missing_docs_in_private_items)]
     _direction: PhantomData<Direction>,
 }
@@ -139,12 +146,48 @@ impl<'a, Message, Renderer, Direction, Theme> Wrap<'a, Message, Direction, Theme
     }
 
     /// Sets the alignment of the [`Wrap`].
+    ///
+    /// The elements are aligned across their line, to the tallest element of
+    /// a horizontal line and to the widest element of a vertical one.
     #[must_use]
     pub const fn align_items(mut self, align: Alignment) -> Self {
         self.alignment = align;
         self
     }
 
+    /// Sets how the free space of each line of the [`Wrap`] is distributed
+    /// along it, like the CSS `justify-content`.
+    #[must_use]
+    pub const fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Sets the spacing and alignment of the [`Wrap`] from the width available
+    /// to it, as pairs of a minimum width and the [`Arrangement`] applying
+    /// from it on.
+    ///
+    /// Below the smallest breakpoint the spacing and alignment set on the
+    /// [`Wrap`] itself are used.
+    #[must_use]
+    pub fn breakpoints(
+        mut self,
+        breakpoints: impl IntoIterator<Item = (f32, Arrangement)>,
+    ) -> Self {
+        self.breakpoints = Breakpoints::new(breakpoints);
+        self
+    }
+
+    /// Returns the [`Arrangement`] of the [`Wrap`] for the given available width.
+    fn arrangement(&self, width: f32) -> Arrangement {
+        self.breakpoints.get(width).copied().unwrap_or(Arrangement {
+            spacing: self.spacing,
+            line_spacing: self.line_spacing,
+            alignment: self.alignment,
+            justify: self.justify,
+        })
+    }
+
     /// Pushes an [`Element`] to the [`Wrap`].
     #[must_use]
     pub fn push<E>(mut self, element: E) -> Self
@@ -320,6 +363,7 @@ impl<'a, Message, Renderer, Direction, Theme> Default
         Self {
             elements: vec![],
             alignment: Alignment::Start,
+            justify: Justify::Start,
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: 4_294_967_295.0,
@@ -328,10 +372,128 @@ impl<'a, Message, Renderer, Direction, Theme> Default
             spacing: Pixels::ZERO,
             line_spacing: Pixels::ZERO,
             line_minimal_length: 10.0,
+            breakpoints: Breakpoints::default(),
             _direction: PhantomData,
         }
     }
 }
+
+/// The spacing and alignment of a [`Wrap`] applying from a breakpoint on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arrangement {
+    /// The spacing between each element of the [`Wrap`].
+    pub spacing: Pixels,
+    /// The spacing between each line of the [`Wrap`].
+    pub line_spacing: Pixels,
+    /// The alignment of the elements within their line.
+    pub alignment: Alignment,
+    /// The distribution of the free space of each line.
+    pub justify: Justify,
+}
+
+impl Arrangement {
+    /// Creates an [`Arrangement`] without spacing, aligning the elements to the start.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            spacing: Pixels::ZERO,
+            line_spacing: Pixels::ZERO,
+            alignment: Alignment::Start,
+            justify: Justify::Start,
+        }
+    }
+
+    /// Sets the spacing between each element.
+    #[must_use]
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Sets the spacing between each line.
+    #[must_use]
+    pub fn line_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.line_spacing = spacing.into();
+        self
+    }
+
+    /// Sets the alignment of the elements within their line.
+    #[must_use]
+    pub const fn align_items(mut self, align: Alignment) -> Self {
+        self.alignment = align;
+        self
+    }
+
+    /// Sets the distribution of the free space of each line.
+    #[must_use]
+    pub const fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+}
+
+impl Default for Arrangement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The distribution of the free space of a line of a [`Wrap`] along it, like
+/// the CSS `justify-content`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Justify {
+    /// Packs the elements at the start of the line.
+    #[default]
+    Start,
+    /// Packs the elements in the center of the line.
+    Center,
+    /// Packs the elements at the end of the line.
+    End,
+    /// Puts the free space between the elements, the first and last element
+    /// touching the ends of the line.
+    SpaceBetween,
+    /// Puts the free space around every element, the space between two
+    /// elements being twice the space at the ends of the line.
+    SpaceAround,
+    /// Puts the same free space between the elements and at the ends of the line.
+    SpaceEvenly,
+}
+
+impl Justify {
+    /// Returns the offset of the first of `count` elements and the extra space
+    /// between two of them distributing the `free` space of a line.
+    #[allow(clippy::cast_precision_loss)]
+    fn distribute(self, free: f32, count: usize) -> (f32, f32) {
+        let free = free.max(0.0);
+        let count = count as f32;
+
+        match self {
+            Self::Start => (0.0, 0.0),
+            Self::Center => (free / 2.0, 0.0),
+            Self::End => (free, 0.0),
+            Self::SpaceBetween => (0.0, free / (count - 1.0).max(1.0)),
+            Self::SpaceAround => (free / count / 2.0, free / count),
+            Self::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+        }
+    }
+}
+
+/// Moves the nodes of a line along it to distribute the free space of a line
+/// of the given length, with `main` the unit vector along the line.
+fn justify_line(line: &mut [Node], justify: Justify, length: f32, main: Vector) {
+    let (Some(first), Some(last)) = (line.first(), line.last()) else {
+        return;
+    };
+    let bounds = first.bounds().union(&last.bounds());
+    let used = bounds.width * main.x + bounds.height * main.y;
+
+    let (mut offset, gap) = justify.distribute(length - used, line.len());
+    for node in line {
+        node.translate_mut(main * offset);
+        offset += gap;
+    }
+}
+
 /// A inner layout of the [`Wrap`].
 pub trait WrapLayout<Renderer>
 where
@@ -350,8 +512,6 @@ where
     #[inline(always)]
     fn inner_layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
         let padding = self.padding;
-        let spacing = self.spacing;
-        let line_spacing = self.line_spacing;
         #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
         let line_minimal_length = self.line_minimal_length;
         let limits = limits
@@ -361,6 +521,12 @@ where
             .max_width(self.max_width)
             .max_height(self.max_height);
         let max_width = limits.max().width;
+        let Arrangement {
+            spacing,
+            line_spacing,
+            alignment,
+            justify,
+        } = self.arrangement(limits.max().width);
 
         let mut children = tree.children.iter_mut();
         let mut curse = padding.left;
@@ -411,18 +577,24 @@ where
         if end != start {
             align.push((start..end, current_line_height));
         }
-        for (range, max_length) in align {
-            nodes[range].iter_mut().for_each(|node| {
-                let size = node.size();
-                let space = Size::new(size.width, max_length);
-                node.align_mut(Alignment::Start, self.alignment, space);
-            });
-        }
         let (width, height) = (
             max_main - padding.left,
             deep_curse - padding.left + current_line_height,
         );
         let size = limits.resolve(self.width, self.height, Size::new(width, height));
+        for (range, max_length) in align {
+            nodes[range.clone()].iter_mut().for_each(|node| {
+                let size = node.size();
+                let space = Size::new(size.width, max_length);
+                node.align_mut(Alignment::Start, alignment, space);
+            });
+            justify_line(
+                &mut nodes[range],
+                justify,
+                size.width,
+                Vector::new(1.0, 0.0),
+            );
+        }
 
         Node::with_children(size.expand(padding), nodes)
     }
@@ -437,8 +609,6 @@ where
     #[inline(always)]
     fn inner_layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
         let padding = self.padding;
-        let spacing = self.spacing;
-        let line_spacing = self.line_spacing;
         #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
         let line_minimal_length = self.line_minimal_length;
         let limits = limits
@@ -448,6 +618,12 @@ where
             .max_width(self.max_width)
             .max_height(self.max_height);
         let max_height = limits.max().height;
+        let Arrangement {
+            spacing,
+            line_spacing,
+            alignment,
+            justify,
+        } = self.arrangement(limits.max().width);
 
         let mut children = tree.children.iter_mut();
         let mut curse = padding.left;
@@ -499,20 +675,26 @@ where
             align.push((start..end, current_line_width));
         }
 
-        for (range, max_length) in align {
-            nodes[range].iter_mut().for_each(|node| {
-                let size = node.size();
-                let space = Size::new(max_length, size.height);
-                node.align_mut(self.alignment, Alignment::Start, space);
-            });
-        }
-
         let (width, height) = (
             wide_curse - padding.left + current_line_width,
             max_main - padding.left,
         );
         let size = limits.resolve(self.width, self.height, Size::new(width, height));
 
+        for (range, max_length) in align {
+            nodes[range.clone()].iter_mut().for_each(|node| {
+                let size = node.size();
+                let space = Size::new(max_length, size.height);
+                node.align_mut(alignment, Alignment::Start, space);
+            });
+            justify_line(
+                &mut nodes[range],
+                justify,
+                size.height,
+                Vector::new(0.0, 1.0),
+            );
+        }
+
         Node::with_children(size.expand(padding), nodes)
     }
 }
@@ -526,3 +708,20 @@ pub mod direction {
     #[derive(Debug)]
     pub struct Horizontal;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Justify;
+
+    #[test]
+    fn distribute_test() {
+        assert_eq!(Justify::Start.distribute(60.0, 3), (0.0, 0.0));
+        assert_eq!(Justify::Center.distribute(60.0, 3), (30.0, 0.0));
+        assert_eq!(Justify::End.distribute(60.0, 3), (60.0, 0.0));
+        assert_eq!(Justify::SpaceBetween.distribute(60.0, 3), (0.0, 30.0));
+        assert_eq!(Justify::SpaceBetween.distribute(60.0, 1).0, 0.0);
+        assert_eq!(Justify::SpaceAround.distribute(60.0, 3), (10.0, 20.0));
+        assert_eq!(Justify::SpaceEvenly.distribute(60.0, 3), (15.0, 15.0));
+        assert_eq!(Justify::End.distribute(-10.0, 3), (0.0, 0.0));
+    }
+}